@@ -0,0 +1,275 @@
+use crate::failure;
+use crate::report::Report;
+use alloc::{format, string::{String, ToString}, vec, vec::Vec};
+use core::fmt;
+
+//=============================================================================================
+// Macros
+//=============================================================================================
+
+/// Asserts that a boolean expression is true, drawing the value of every sub-expression under
+/// it on failure.
+///
+/// The expression is decomposed into its binary operators, unary operators, casts, method calls,
+/// function calls, field accesses and indexing. Each sub-expression is evaluated exactly once,
+/// in source order, and the right operand of `&&` or `||` only if it is reached. Closures,
+/// blocks, macro invocations and other syntax are kept whole, and expressions that start with a
+/// keyword like `if` or `match` are checked without being decomposed.
+///
+/// ### Parameters
+///
+/// - `expression` The boolean expression to check.
+/// - `format` (Optional) A format string and arguments, like [assert](https://doc.rust-lang.org/std/macro.assert.html).
+///
+/// ### Dependencies
+///
+/// - Sub-expressions are drawn if they implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html), and left blank otherwise.
+/// - Results of `into`, `parse`, `collect`, `sum`, `product` and `default` are not drawn, since
+///   their types are often only known from how they are used.
+///
+/// ### Example
+///
+/// ```
+/// use totems::check;
+/// let a = vec![1, 2, 3];
+/// let b = vec![3, 2, 1];
+/// check!(a.len() == b.len() && a[0] < b[0]);
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'check::checks::incorrect' panicked at 'assertion failed:
+/// a.len() == b.len()
+/// | |     |  | |
+/// | 3     |  | 2
+/// |       |  [1, 2]
+/// |       false
+/// [1, 2, 3]'
+/// ```
+#[macro_export]
+macro_rules! check {
+    ($($input:tt)*) => {
+        $crate::check::decompose!([$crate] $($input)*)
+    };
+}
+
+/// Decomposes the expression of [check](../macro.check.html), which passes `$crate` ahead of it so
+/// the generated code does not depend on the name the crate was imported under.
+#[doc(hidden)]
+pub use totems_macros::check as decompose;
+
+//=============================================================================================
+// Helpers
+//=============================================================================================
+
+/// The values recorded by [check](../macro.check.html), each under the column it belongs to.
+#[doc(hidden)]
+pub type Log = Vec<(usize, Option<String>)>;
+
+/// Borrows a sub-expression of [check](../macro.check.html) for rendering.
+///
+/// Rendering uses `Debug` when the value implements it, and falls back to nothing otherwise. The
+/// fallback is implemented for `&Render`, so method resolution only reaches it after finding
+/// that `RenderDebug` does not apply.
+#[doc(hidden)]
+pub struct Render<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait RenderDebug {
+    fn render(&self) -> Option<String>;
+}
+
+impl<T: fmt::Debug + ?Sized> RenderDebug for Render<'_, T> {
+    fn render(&self) -> Option<String> {
+        Some(format!("{:?}", self.0))
+    }
+}
+
+#[doc(hidden)]
+pub trait RenderFallback {
+    fn render(&self) -> Option<String>;
+}
+
+impl<T: ?Sized> RenderFallback for &Render<'_, T> {
+    fn render(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Fails [check](../macro.check.html), with the diagram of `expression` as the details of its report.
+#[doc(hidden)]
+#[track_caller]
+pub fn fail(expression: &str, values: &[(usize, Option<String>)], message: Option<fmt::Arguments>) -> ! {
+    let diagram = diagram(expression, values);
+    match message {
+        Some(message) => failure::fail("check", &Report::new(format_args!(""), &[]).source("", &[expression]).details(format_args!("{}", diagram)).message(message)),
+        None => failure::fail("check", &Report::new(format_args!(""), &[]).source("", &[expression]).details(format_args!("{}", diagram))),
+    }
+}
+
+/// Draws each value under the column of `expression` it belongs to.
+///
+/// Every value hangs from a `|` under its column. Rows are filled from the right, and a value is
+/// placed as soon as it fits before the next column to its right.
+#[doc(hidden)]
+pub fn diagram(expression: &str, values: &[(usize, Option<String>)]) -> String {
+    let mut pending: Vec<(usize, &str)> = values
+        .iter()
+        .filter_map(|(column, value)| value.as_ref().map(|value| (*column, value.as_str())))
+        .collect();
+    pending.sort_by_key(|&(column, _)| column);
+    pending.dedup_by_key(|&mut (column, _)| column);
+
+    if pending.is_empty() {
+        return expression.to_string();
+    }
+    let mut lines = vec![expression.to_string()];
+    let width = pending
+        .iter()
+        .map(|(column, value)| column + value.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut bars = vec![' '; width];
+    for &(column, _) in &pending {
+        bars[column] = '|';
+    }
+    lines.push(bars.into_iter().collect());
+
+    while !pending.is_empty() {
+        let mut row = vec![' '; width];
+        let mut hanging = Vec::new();
+        let mut boundary = usize::MAX;
+        for &(column, value) in pending.iter().rev() {
+            if column + value.chars().count() < boundary {
+                for (i, c) in value.chars().enumerate() {
+                    row[column + i] = c;
+                }
+            } else {
+                row[column] = '|';
+                hanging.push((column, value));
+            }
+            boundary = column;
+        }
+        hanging.reverse();
+        pending = hanging;
+        lines.push(row.into_iter().collect());
+    }
+
+    lines.iter().map(|line| line.trim_end()).collect::<Vec<_>>().join("\n")
+}
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod checks {
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Point {
+        fn norm(&self) -> i32 {
+            self.x.abs() + self.y.abs()
+        }
+    }
+
+    struct Opaque(i32);
+
+    #[test]
+    fn correct() {
+        let (a, b) = (vec![1, 2, 3], vec![3, 2, 1]);
+        let point = Point { x: 3, y: -4 };
+        let pair = (1, (2, 3));
+        check!(a.len() == b.len() && a[0] < b[0]);
+        check!(point.norm() == 7 && point.x as i64 + 1 == 4);
+        check!(!a.is_empty());
+        check!(pair.1.0 < pair.1.1);
+        check!(a[1..] == [2, 3] && b[..1].len() == 1);
+        check!(a.iter().map(|x| x * 2).sum::<i32>() == 12);
+        check!(Opaque(5).0 > 4);
+        check!(a.iter().copied().collect::<Vec<_>>() == vec![1, 2, 3]);
+        check!(String::from("ab") == "ab".to_string());
+        check!(a.contains(&2), "{:?} contains 2", a);
+        check!(if a.is_empty() { false } else { a[0] == 1 });
+    }
+    #[test]
+    fn inferred_types() {
+        let big: u64 = 5;
+        let number: Result<i32, _> = "42".parse();
+        check!(big == 5u32.into());
+        check!(number == "42".parse());
+        check!(0 == Default::default());
+    }
+    #[test]
+    fn evaluates_once() {
+        let mut calls = 0;
+        let mut next = || {
+            calls += 1;
+            calls
+        };
+        check!(next() == 1 || next() == 2);
+        assert_eq!(calls, 1);
+    }
+    #[test]
+    fn question_mark() -> Result<(), std::num::ParseIntError> {
+        check!("7".parse::<i32>()? == 7);
+        Ok(())
+    }
+    #[test]
+    #[should_panic(expected = "assertion failed:\na.len() == b.len()\n| |     |  | |\n| 3     |  | 2\n")]
+    fn incorrect() {
+        let (a, b) = (vec![1, 2, 3], vec![1, 2]);
+        check!(a.len() == b.len());
+    }
+    #[test]
+    #[should_panic(expected = "assertion failed: no origin\n")]
+    fn format_string() {
+        let point = Point { x: 0, y: 0 };
+        check!(point.norm() > 0, "no {}", "origin");
+    }
+    #[test]
+    #[should_panic(expected = "Opaque(1).0 == 2\n          | |\n          1 false")]
+    fn without_debug() {
+        check!(Opaque(1).0 == 2);
+    }
+    #[test]
+    #[should_panic(expected = "assertion failed:\nmatch v.len() { 0 => false, n => n > 1 }")]
+    fn not_decomposed() {
+        let v = [1];
+        check!(match v.len() { 0 => false, n => n > 1 });
+    }
+}
+
+#[cfg(test)]
+mod diagrams {
+    use super::diagram;
+
+    #[test]
+    fn nested() {
+        let values = [
+            (0, Some(String::from("[1, 2, 3]"))),
+            (2, Some(String::from("3"))),
+            (8, Some(String::from("false"))),
+            (11, Some(String::from("[1, 2]"))),
+            (13, Some(String::from("2"))),
+        ];
+        assert_eq!(
+            diagram("a.len() == b.len()", &values),
+            "a.len() == b.len()\n| |     |  | |\n| 3     |  | 2\n|       |  [1, 2]\n|       false\n[1, 2, 3]",
+        );
+    }
+    #[test]
+    fn blank_values() {
+        let values = [(0, None), (6, Some(String::from("true")))];
+        assert_eq!(diagram("a.b() && c", &values), "a.b() && c\n      |\n      true");
+    }
+    #[test]
+    fn empty() {
+        assert_eq!(diagram("true == false", &[]), "true == false");
+    }
+}
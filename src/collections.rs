@@ -1,3 +1,12 @@
+use core::fmt;
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "alloc")]
+use crate::inequalities::{approx_eq, Float, Tolerance};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, format, string::{String, ToString}, vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+
 //=============================================================================================
 // Macros
 //=============================================================================================
@@ -7,13 +16,15 @@
 /// ### Parameters
 /// 
 /// - `&collection` A reference to a collection.
-/// - `position` The position in the collection (starts at 0).
-/// - `&val` A reference to a value to compare to the nth item.
+/// - `position` The position in the collection (starts at 0), or `-position` to count from the end (starts at -1).
+/// - `&val` A reference to a value to compare to the nth item, or
+///   `predicate` A closure or function that takes a reference to the nth item and returns a boolean.
 /// 
 /// ### Dependencies
 /// 
 /// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
 /// - `&collection` must implement [IntoIterator](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
+/// - Its iterator must implement [DoubleEndedIterator](https://doc.rust-lang.org/std/iter/trait.DoubleEndedIterator.html) to use a negative `position`.
 /// - `val` must implement PartialEq for the types in `collection` to use `==` or `!=`.
 /// - `val` must implement PartialOrd for the types in `collection` to use `<`, `<=`, `>`, `>=`.
 /// 
@@ -28,123 +39,526 @@
 /// assert_nth!(&vec, 2, value >= &x);
 /// assert_nth!(&vec, 2, value < &(x + 1));
 /// assert_nth!(&vec, 2, value > &(x - 1));
+/// assert_nth!(&vec, -1, value == &19); // vec[vec.len() - 1] == 19
+/// ```
+///
+/// **Check the nth item with a predicate, and get it back:**
+/// ```
+/// use totems::assert_nth;
+/// let words = vec!["apple", "banana", "cherry"];
+/// let word = assert_nth!(&words, 1, value => |w| w.starts_with('b'));
+/// assert_eq!(word, &"banana");
 /// ```
 ///
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'collections::nth::le_correct' panicked at 'assertion failed: (collection[3] <= item)
-///          item: 5
-/// collection[3]: 7
-/// ', src/collections.rs:388:9
+/// thread 'collections::nth::le_correct' panicked at 'assertion failed: `value <= &x` where `value = &vec[3]`
+///   left: 7
+///  right: 5'
+/// ```
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `value` matches `|w| w.starts_with('c')` where `value = &words[1]`
+///  value: "banana"'
 /// ```
 #[macro_export]
 macro_rules! assert_nth {
-    ($collection:expr, $position:expr, value == $val:expr) => {
-        match $collection.into_iter().nth($position) {
+    ($collection:expr, -$position:expr, value == $val:expr) => {
+        match $crate::collections::nth_back($collection, $position) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value != *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value == {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source("==", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value == {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("==", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[-{}]` does not exist", stringify!($collection), stringify!($position))));
+            }
+        }
+    };
+    ($collection:expr, -$position:expr, value != $val:expr) => {
+        match $crate::collections::nth_back($collection, $position) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value == *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value != {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source("!=", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value != {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("!=", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[-{}]` does not exist", stringify!($collection), stringify!($position))));
+            }
+        }
+    };
+    ($collection:expr, -$position:expr, value < $val:expr) => {
+        match $crate::collections::nth_back($collection, $position) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value >= *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value < {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source("<", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value < {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("<", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[-{}]` does not exist", stringify!($collection), stringify!($position))));
+            }
+        }
+    };
+    ($collection:expr, -$position:expr, value <= $val:expr) => {
+        match $crate::collections::nth_back($collection, $position) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value > *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value <= {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source("<=", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value <= {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("<=", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[-{}]` does not exist", stringify!($collection), stringify!($position))));
+            }
+        }
+    };
+    ($collection:expr, -$position:expr, value > $val:expr) => {
+        match $crate::collections::nth_back($collection, $position) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value <= *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value > {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source(">", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value > {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source(">", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[-{}]` does not exist", stringify!($collection), stringify!($position))));
+            }
+        }
+    };
+    ($collection:expr, -$position:expr, value >= $val:expr) => {
+        match $crate::collections::nth_back($collection, $position) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value < *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value >= {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source(">=", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value >= {}` where `value = {}[-{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source(">=", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[-{}]` does not exist", stringify!($collection), stringify!($position))));
+            }
+        }
+    };
+    ($collection:expr, -$position:expr, value => $predicate:expr) => {
+        match $crate::collections::nth_back($collection, $position) {
             Some(value) => {
-                if value != $val {
-                    panic!("assertion failed: (collection[{0}] == item)\n         item: {1:?}\ncollection[{0}]: {2:?}\n",
-                        $position,
-                        $val,
-                        value,
-                    )
+                if !$crate::collections::matches(&value, $predicate) {
+                    $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                        format_args!("`value` matches `{}` where `value = {}[-{}]`", stringify!($predicate), stringify!($collection), stringify!($position)),
+                        &[
+                            $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", value)),
+                        ],
+                    ).source("matches", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($predicate)]))
                 }
+                value
             }
             None => {
-                panic!("assertion failed: (collection[{0}] == item)\n collection[{0}] does not exist\n",
-                    $position,
-                );
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value` matches `{}` where `value = {}[-{}]`", stringify!($predicate), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("matches", &[concat!(stringify!($collection), "[-", stringify!($position), "]"), stringify!($predicate)]).details(format_args!(" `{}[-{}]` does not exist", stringify!($collection), stringify!($position))));
             }
         }
     };
-    ($collection:expr, $position:expr, value != $val:expr) => {
+    ($collection:expr, $position:expr, value == $val:expr) => {
         match $collection.into_iter().nth($position) {
-            Some(value) => {
-                if value == $val {
-                    panic!("assertion failed: (collection[{0}] != item)\n         item: {1:?}\ncollection[{0}]: {2:?}\n",
-                        $position,
-                        $val,
-                        value,
-                    )
+            Some(value) => match (&$val) {
+                expected => {
+                    if value != *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value == {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source("==", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
                 }
+            },
+            None => {
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value == {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("==", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($position))));
             }
+        }
+    };
+    ($collection:expr, $position:expr, value != $val:expr) => {
+        match $collection.into_iter().nth($position) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value == *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value != {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source("!=", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
             None => {
-                panic!("assertion failed: (collection[{0}] != item)\n collection[{0}] does not exist\n",
-                    $position,
-                );
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value != {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("!=", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($position))));
             }
         }
     };
     ($collection:expr, $position:expr, value < $val:expr) => {
         match $collection.into_iter().nth($position) {
-            Some(value) => {
-                if value >= $val {
-                    panic!("assertion failed: (collection[{0}] < item)\n         item: {1:?}\ncollection[{0}]: {2:?}\n",
-                        $position,
-                        $val,
-                        value,
-                    )
+            Some(value) => match (&$val) {
+                expected => {
+                    if value >= *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value < {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source("<", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
                 }
-            }
+            },
             None => {
-                panic!("assertion failed: (collection[{0}] < item)\n collection[{0}] does not exist\n",
-                    $position,
-                );
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value < {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("<", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($position))));
             }
         }
     };
     ($collection:expr, $position:expr, value <= $val:expr) => {
         match $collection.into_iter().nth($position) {
-            Some(value) => {
-                if value > $val {
-                    panic!("assertion failed: (collection[{0}] <= item)\n         item: {1:?}\ncollection[{0}]: {2:?}\n",
-                        $position,
-                        $val,
-                        value,
-                    )
+            Some(value) => match (&$val) {
+                expected => {
+                    if value > *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value <= {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source("<=", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
                 }
-            }
+            },
             None => {
-                panic!("assertion failed: (collection[{0}] <= item)\n collection[{0}] does not exist\n",
-                    $position,
-                );
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value <= {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("<=", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($position))));
             }
         }
     };
     ($collection:expr, $position:expr, value > $val:expr) => {
         match $collection.into_iter().nth($position) {
-            Some(value) => {
-                if value <= $val {
-                    panic!("assertion failed: (collection[{0}] > item)\n         item: {1:?}\ncollection[{0}]: {2:?}\n",
-                        $position,
-                        $val,
-                        value,
-                    )
+            Some(value) => match (&$val) {
+                expected => {
+                    if value <= *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value > {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source(">", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
                 }
-            }
+            },
             None => {
-                panic!("assertion failed: (collection[{0}] > item)\n collection[{0}] does not exist\n",
-                    $position,
-                );
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value > {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source(">", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($position))));
             }
         }
     };
     ($collection:expr, $position:expr, value >= $val:expr) => {
+        match $collection.into_iter().nth($position) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value < *expected {
+                        $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                            format_args!("`value >= {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                            ],
+                        ).source(">=", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value >= {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source(">=", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($position))));
+            }
+        }
+    };
+    ($collection:expr, $position:expr, value => $predicate:expr) => {
         match $collection.into_iter().nth($position) {
             Some(value) => {
-                if value < $val {
-                    panic!("assertion failed: (collection[{0}] >= item)\n         item: {1:?}\ncollection[{0}]: {2:?}\n",
-                        $position,
-                        $val,
-                        value,
-                    )
+                if !$crate::collections::matches(&value, $predicate) {
+                    $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                        format_args!("`value` matches `{}` where `value = {}[{}]`", stringify!($predicate), stringify!($collection), stringify!($position)),
+                        &[
+                            $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", value)),
+                        ],
+                    ).source("matches", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($predicate)]))
+                }
+                value
+            }
+            None => {
+                $crate::failure::fail("assert_nth", &$crate::report::Report::new(
+                    format_args!("`value` matches `{}` where `value = {}[{}]`", stringify!($predicate), stringify!($collection), stringify!($position)),
+                    &[],
+                ).source("matches", &[concat!(stringify!($collection), "[", stringify!($position), "]"), stringify!($predicate)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($position))));
+            }
+        }
+    };
+}
+
+/// Asserts that a `range` of a `collection` has a relationship to some slice.
+///
+/// ### Parameters
+///
+/// - `&collection` A reference to a slice, array or `Vec`.
+/// - `range` The range of positions to compare, such as `2..5`, `..3` or `7..`.
+/// - `&slice` A reference to a slice to compare to the range, preceded by `==`, `!=`, `<`, `<=`, `>` or `>=`.
+///
+/// ### Dependencies
+///
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `collection` must dereference to a [slice](https://doc.rust-lang.org/std/primitive.slice.html).
+/// - `slice` must implement PartialEq for the slice type to use `==` or `!=`.
+/// - `slice` must be a slice of the same type to use `<`, `<=`, `>`, `>=`, which compare lexicographically.
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_slice;
+/// let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+/// assert_slice!(&vec, 2..5, == &[5, 7, 9]); // vec[2..5] == [5, 7, 9]
+/// assert_slice!(&vec, ..2, != &[1, 2]);
+/// assert_slice!(&vec, 8.., <  &[19][..]);
+/// assert_slice!(&vec, 8.., <= &[17, 19][..]);
+/// assert_slice!(&vec, 8.., >  &[17][..]);
+/// assert_slice!(&vec, 8.., >= &[17, 19][..]);
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `value == &[5, 7, 8]` where `value = &vec[2..5]`
+///   left: [5, 7, 9]
+///  right: [5, 7, 8]'
+/// ```
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `value == &[17, 19]` where `value = &vec[8..12]`
+///  `&vec[8..12]` does not exist'
+/// ```
+#[macro_export]
+macro_rules! assert_slice {
+    ($collection:expr, $range:expr, == $val:expr) => {
+        match $collection.get($range) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value != *expected {
+                        $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                            format_args!("`value == {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(value)))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(*expected)))),
+                            ],
+                        ).source("==", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$crate::collections::Truncated(value), &$crate::collections::Truncated(*expected)))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                    format_args!("`value == {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                    &[],
+                ).source("==", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($range))));
+            }
+        }
+    };
+    ($collection:expr, $range:expr, != $val:expr) => {
+        match $collection.get($range) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value == *expected {
+                        $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                            format_args!("`value != {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(value)))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(*expected)))),
+                            ],
+                        ).source("!=", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$crate::collections::Truncated(value), &$crate::collections::Truncated(*expected)))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                    format_args!("`value != {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                    &[],
+                ).source("!=", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($range))));
+            }
+        }
+    };
+    ($collection:expr, $range:expr, < $val:expr) => {
+        match $collection.get($range) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value >= *expected {
+                        $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                            format_args!("`value < {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(value)))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(*expected)))),
+                            ],
+                        ).source("<", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$crate::collections::Truncated(value), &$crate::collections::Truncated(*expected)))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                    format_args!("`value < {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                    &[],
+                ).source("<", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($range))));
+            }
+        }
+    };
+    ($collection:expr, $range:expr, <= $val:expr) => {
+        match $collection.get($range) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value > *expected {
+                        $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                            format_args!("`value <= {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(value)))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(*expected)))),
+                            ],
+                        ).source("<=", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$crate::collections::Truncated(value), &$crate::collections::Truncated(*expected)))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                    format_args!("`value <= {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                    &[],
+                ).source("<=", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($range))));
+            }
+        }
+    };
+    ($collection:expr, $range:expr, > $val:expr) => {
+        match $collection.get($range) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value <= *expected {
+                        $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                            format_args!("`value > {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(value)))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(*expected)))),
+                            ],
+                        ).source(">", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$crate::collections::Truncated(value), &$crate::collections::Truncated(*expected)))))
+                    }
                 }
+            },
+            None => {
+                $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                    format_args!("`value > {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                    &[],
+                ).source(">", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($range))));
             }
+        }
+    };
+    ($collection:expr, $range:expr, >= $val:expr) => {
+        match $collection.get($range) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value < *expected {
+                        $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                            format_args!("`value >= {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(value)))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$crate::collections::Truncated(*expected)))),
+                            ],
+                        ).source(">=", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$crate::collections::Truncated(value), &$crate::collections::Truncated(*expected)))))
+                    }
+                }
+            },
             None => {
-                panic!("assertion failed: (collection[{0}] >= item)\n collection[{0}] does not exist\n",
-                    $position,
-                );
+                $crate::failure::fail("assert_slice", &$crate::report::Report::new(
+                    format_args!("`value >= {}` where `value = {}[{}]`", stringify!($val), stringify!($collection), stringify!($range)),
+                    &[],
+                ).source(">=", &[concat!(stringify!($collection), "[", stringify!($range), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($collection), stringify!($range))));
             }
         }
     };
@@ -155,7 +569,8 @@ macro_rules! assert_nth {
 /// ### Parameters
 /// 
 /// - `&collection` A reference to a collection.
-/// - `&item` A reference to an item to compare to items in the collection.
+/// - `&item` A reference to an item to compare to items in the collection, or
+///   `where predicate` A closure or function that takes a reference to an item and returns a boolean.
 /// 
 /// ### Dependencies
 /// 
@@ -163,31 +578,65 @@ macro_rules! assert_nth {
 /// - `&collection` must implement [IntoIterator](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
 /// - `item` must implement PartialEq for the types in `collection`.
 /// 
-/// ### Example
+/// ### Examples
 ///
+/// **Check for an equal item:**
 /// ```
 /// use totems::assert_contains;
 /// let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
 /// let x = 5;
 /// assert_contains!(&vec, &x);
 /// ```
+/// **Check for an item matching a predicate, and get the first match back:**
+/// ```
+/// use totems::assert_contains;
+/// let pairs = vec![(1, "one"), (7, "seven"), (9, "nine")];
+/// let pair = assert_contains!(&pairs, where |p| p.0 == 7);
+/// assert_eq!(pair.1, "seven");
+/// ```
 ///
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'main' panicked at 'assertion failed: (collection contains item)
-///        item: 2
-///  collection: [1, 3, 5, 7, 9, 11, 13, 15, 17, 19]
-/// ', src/collections.rs:149:9
+/// thread 'main' panicked at 'assertion failed: `&vec` contains `&x`
+///   left: [1, 3, 5, 7, 9, 11, 13, 15, 17, 19]
+///  right: 2'
+/// ```
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `&pairs` contains an item matching `|p| p.0 == 8`
+///  value: [(1, "one"), (7, "seven"), (9, "nine")]'
 /// ```
 #[macro_export]
 macro_rules! assert_contains {
+    ($collection:expr, where $predicate:expr) => {
+        match $collection {
+            collection => match $crate::collections::find(::core::clone::Clone::clone(&collection), $predicate) {
+                $crate::collections::Scan::Passed(item) => item,
+                scan => {
+                    $crate::failure::fail("assert_contains", &$crate::report::Report::new(
+                        format_args!("`{}` contains an item matching `{}`", stringify!($collection), stringify!($predicate)),
+                        &[
+                            $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", $crate::collections::Truncated(collection))),
+                        ],
+                    ).source("contains matching", &[stringify!($collection), stringify!($predicate)]).details(format_args!("{}", scan)));
+                }
+            },
+        }
+    };
     ($collection:expr, $item:expr) => {
-        if let None = $collection.into_iter().find(|&x| x == $item) {
-            panic!("assertion failed: (collection contains item)\n       item: {:?}\n collection: {:?}\n",
-                    $item,
-                    $collection,
-            );
+        match ($collection, &$item) {
+            (collection, item) => match $crate::collections::find(::core::clone::Clone::clone(&collection), |&x| x == *item) {
+                $crate::collections::Scan::Passed(_) => {}
+                scan => {
+                    $crate::failure::fail("assert_contains", &$crate::report::Report::new(
+                        format_args!("`{}` contains `{}`", stringify!($collection), stringify!($item)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::collections::Truncated(collection))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", item)),
+                        ],
+                    ).source("contains", &[stringify!($collection), stringify!($item)]).details(format_args!("{}", scan)));
+                }
+            },
         }
     };
 }
@@ -216,26 +665,39 @@ macro_rules! assert_contains {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'main' panicked at 'assertion failed: (all elements of collection match predicate)
-///   predicate: all < 0
-///  collection: [-1, 3, 5, 7, 9, 11, 13, 15, 17, 19]
-/// ', src/collections.rs:165:9
+/// thread 'main' panicked at 'assertion failed: all items of `&vec` match `|&x| x < 0`: all < 0
+///  value: [-1, 3, 5, 7, 9, 11, 13, 15, 17, 19]'
 /// ```
 #[macro_export]
 macro_rules! assert_all {
     ($collection:expr, $predicate:expr) => {
-        if false == $collection.into_iter().all($predicate) {
-            panic!("assertion failed: (all elements of collection match predicate) collection: {:?}\n",
-                $collection,
-            )
+        match $collection {
+            collection => match $crate::collections::scan_all(::core::clone::Clone::clone(&collection), $predicate) {
+                $crate::collections::Scan::Passed(()) => {}
+                scan => {
+                    $crate::failure::fail("assert_all", &$crate::report::Report::new(
+                        format_args!("all items of `{}` match `{}`", stringify!($collection), stringify!($predicate)),
+                        &[
+                            $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", $crate::collections::Truncated(collection))),
+                        ],
+                    ).source("all match", &[stringify!($collection), stringify!($predicate)]).details(format_args!("{}", scan)))
+                }
+            },
         }
     };
     ($collection:expr, $predicate:expr, $($arg:tt)+) => {
-        if false == $collection.into_iter().all($predicate) {
-            panic!("assertion failed: (all elements of collection match predicate)\n  predicate: {}\n collection: {:?}\n",
-                format_args!($($arg)+),
-                $collection,
-            )
+        match $collection {
+            collection => match $crate::collections::scan_all(::core::clone::Clone::clone(&collection), $predicate) {
+                $crate::collections::Scan::Passed(()) => {}
+                scan => {
+                    $crate::failure::fail("assert_all", &$crate::report::Report::new(
+                        format_args!("all items of `{}` match `{}`", stringify!($collection), stringify!($predicate)),
+                        &[
+                            $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", $crate::collections::Truncated(collection))),
+                        ],
+                    ).source("all match", &[stringify!($collection), stringify!($predicate)]).details(format_args!("{}", scan)).message(format_args!($($arg)+)))
+                }
+            },
         }
     }
 }
@@ -264,228 +726,2475 @@ macro_rules! assert_all {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'main' panicked at 'assertion failed: (any element of collection matches predicate)
-///   predicate: any < 0
-///  collection: [1, 3, 5, 7, 9, 11, 13, 15, 17, 19]
-/// ', src/collections.rs:188:9
+/// thread 'main' panicked at 'assertion failed: any item of `&vec` matches `|&x| x < 0`: any < 0
+///  value: [1, 3, 5, 7, 9, 11, 13, 15, 17, 19]'
 /// ```
 #[macro_export]
 macro_rules! assert_any {
     ($collection:expr, $predicate:expr) => {
-        if false == $collection.into_iter().any($predicate) {
-            panic!("assertion failed: (any element of collection matches predicate) collection: {:?}\n",
-                $collection,
-            )
+        match $collection {
+            collection => match $crate::collections::scan_any(::core::clone::Clone::clone(&collection), $predicate) {
+                $crate::collections::Scan::Passed(()) => {}
+                scan => {
+                    $crate::failure::fail("assert_any", &$crate::report::Report::new(
+                        format_args!("any item of `{}` matches `{}`", stringify!($collection), stringify!($predicate)),
+                        &[
+                            $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", $crate::collections::Truncated(collection))),
+                        ],
+                    ).source("any match", &[stringify!($collection), stringify!($predicate)]).details(format_args!("{}", scan)))
+                }
+            },
         }
     };
     ($collection:expr, $predicate:expr, $($arg:tt)+) => {
-        if false == $collection.into_iter().any($predicate) {
-            panic!("assertion failed: (any element of collection matches predicate)\n  predicate: {}\n collection: {:?}\n",
-                format_args!($($arg)+),
-                $collection,
-            )
+        match $collection {
+            collection => match $crate::collections::scan_any(::core::clone::Clone::clone(&collection), $predicate) {
+                $crate::collections::Scan::Passed(()) => {}
+                scan => {
+                    $crate::failure::fail("assert_any", &$crate::report::Report::new(
+                        format_args!("any item of `{}` matches `{}`", stringify!($collection), stringify!($predicate)),
+                        &[
+                            $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", $crate::collections::Truncated(collection))),
+                        ],
+                    ).source("any match", &[stringify!($collection), stringify!($predicate)]).details(format_args!("{}", scan)).message(format_args!($($arg)+)))
+                }
+            },
         }
     }
 }
 
-//=============================================================================================
-// Unit Tests
-//=============================================================================================
-
-#[cfg(test)]
-mod contains {
-    #[test]
-    fn contains_item() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 5;
-        assert_contains!(&vec, &x);
-    }
-
-    #[test]
-    #[should_panic]
-    fn excludes_item() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 2;
-        assert_contains!(&vec, &x);
-    }
+/// Asserts that two collections are equal element by element, printing a diff when they are not.
+///
+/// ### Parameters
+///
+/// - `&actual` A reference to the collection under test.
+/// - `&expected` A reference to the collection it should equal.
+/// - `description` ***(optional)*** A format string describing the assertion.
+///
+/// ### Dependencies
+///
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `&actual` and `&expected` must implement [IntoIterator](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
+/// - The items of `actual` must implement PartialEq for the items of `expected`.
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_seq_eq;
+/// let vec = vec![1, 3, 5, 7, 9];
+/// let arr = [1, 3, 5, 7, 9];
+/// assert_seq_eq!(&vec, &arr);
+/// ```
+///
+/// ### Example Error Messages
+///
+/// Lines starting with `-` come from `actual`, lines starting with `+` come from `expected`.
+/// Elements both share stay aligned, so an inserted or missing element shows as a single line.
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `&vec == &arr`
+///  length: actual has 5 elements, expected has 6
+/// -[2]: 5
+/// +[2]: 4
+/// +[5]: 11'
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_seq_eq {
+    ($actual:expr, $expected:expr) => {
+        if let Some(diff) = $crate::collections::seq_diff($actual, $expected) {
+            $crate::failure::fail("assert_seq_eq", &$crate::report::Report::new(
+                format_args!("`{} == {}`", stringify!($actual), stringify!($expected)),
+                &[],
+            ).source("==", &[stringify!($actual), stringify!($expected)]).details(format_args!("{}", diff)));
+        }
+    };
+    ($actual:expr, $expected:expr,) => {
+        $crate::assert_seq_eq!($actual, $expected)
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        if let Some(diff) = $crate::collections::seq_diff($actual, $expected) {
+            $crate::failure::fail("assert_seq_eq", &$crate::report::Report::new(
+                format_args!("`{} == {}`", stringify!($actual), stringify!($expected)),
+                &[],
+            ).source("==", &[stringify!($actual), stringify!($expected)]).details(format_args!("{}", diff)).message(format_args!($($arg)+)));
+        }
+    };
 }
 
-#[cfg(test)]
-mod nth {
-    #[test]
-    fn eq_correct() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 5;
-        assert_nth!(&vec, 2, value == &x);
-    }
-
-    #[test]
-    #[should_panic]
-    fn eq_incorrect() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
-        assert_nth!(&vec, 2, value == &x);
-    }
-
-    #[test]
-    #[should_panic]
-    fn eq_out_of_range() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
-        assert_nth!(&vec, 20, value == &x);
-    }
-
-    #[test]
-    fn ne_correct() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
-        assert_nth!(&vec, 2, value != &x);
-    }
-
-    #[test]
-    #[should_panic]
-    fn ne_incorrect() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 5;
-        assert_nth!(&vec, 2, value != &x);
-    }
+/// Asserts that a `key` is present in a `map`.
+///
+/// ### Parameters
+///
+/// - `&map` A reference to a map, such as a [HashMap](https://doc.rust-lang.org/std/collections/struct.HashMap.html) or [BTreeMap](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html).
+/// - `&key` A reference to the key to look up.
+///
+/// ### Dependencies
+///
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `map` must have a `contains_key` method that accepts `&key`, and `&map` must iterate over `(key, value)` pairs.
+///
+/// ### Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use totems::assert_contains_key;
+/// let mut map = HashMap::new();
+/// map.insert("one", 1);
+/// assert_contains_key!(&map, &"one");
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `&map` contains key `&"two"`
+///   left: {"one": 1}
+///  right: "two"'
+/// ```
+#[macro_export]
+macro_rules! assert_contains_key {
+    ($map:expr, $key:expr) => {
+        match ($map, $key) {
+            (map, key) => {
+                if !map.contains_key(key) {
+                    $crate::failure::fail("assert_contains_key", &$crate::report::Report::new(
+                        format_args!("`{}` contains key `{}`", stringify!($map), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::collections::TruncatedMap(map))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", key)),
+                        ],
+                    ).source("contains key", &[stringify!($map), stringify!($key)]));
+                }
+            }
+        }
+    };
+}
 
-    #[test]
-    #[should_panic]
-    fn ne_out_of_range() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
-        assert_nth!(&vec, 20, value != &x);
-    }
+/// Asserts that a `key` is absent from a `map`.
+///
+/// ### Parameters
+///
+/// - `&map` A reference to a map, such as a [HashMap](https://doc.rust-lang.org/std/collections/struct.HashMap.html) or [BTreeMap](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html).
+/// - `&key` A reference to the key to look up.
+///
+/// ### Dependencies
+///
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `map` must have a `contains_key` method that accepts `&key`, and `&map` must iterate over `(key, value)` pairs.
+///
+/// ### Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use totems::assert_not_contains_key;
+/// let mut map = BTreeMap::new();
+/// map.insert("one", 1);
+/// assert_not_contains_key!(&map, &"two");
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `&map` does not contain key `&"one"`
+///   left: {"one": 1}
+///  right: "one"'
+/// ```
+#[macro_export]
+macro_rules! assert_not_contains_key {
+    ($map:expr, $key:expr) => {
+        match ($map, $key) {
+            (map, key) => {
+                if map.contains_key(key) {
+                    $crate::failure::fail("assert_not_contains_key", &$crate::report::Report::new(
+                        format_args!("`{}` does not contain key `{}`", stringify!($map), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::collections::TruncatedMap(map))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", key)),
+                        ],
+                    ).source("does not contain key", &[stringify!($map), stringify!($key)]));
+                }
+            }
+        }
+    };
+}
 
-    #[test]
-    fn lt_correct() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
+/// Asserts that the value stored under `key` in a `map` has a relationship to some value.
+///
+/// ### Parameters
+///
+/// - `&map` A reference to a map, such as a [HashMap](https://doc.rust-lang.org/std/collections/struct.HashMap.html) or [BTreeMap](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html).
+/// - `&key` A reference to the key to look up.
+/// - `&val` A reference to a value to compare to the stored value.
+///
+/// ### Dependencies
+///
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `map` must have a `get` method that accepts `&key`.
+/// - `val` must implement PartialEq for the values in `map` to use `==` or `!=`.
+/// - `val` must implement PartialOrd for the values in `map` to use `<`, `<=`, `>`, `>=`.
+///
+/// ### Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use totems::assert_key;
+/// let mut map = HashMap::new();
+/// map.insert("five", 5);
+/// assert_key!(&map, &"five", value == &5); // map["five"] == 5
+/// assert_key!(&map, &"five", value != &4);
+/// assert_key!(&map, &"five", value <  &6);
+/// assert_key!(&map, &"five", value <= &5);
+/// assert_key!(&map, &"five", value >  &4);
+/// assert_key!(&map, &"five", value >= &5);
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `value == &4` where `value = &map[&"five"]`
+///   left: 5
+///  right: 4
+///    key: "five"'
+/// ```
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `value == &6` where `value = &map[&"six"]`
+///  key: "six"
+///  `&map[&"six"]` does not exist'
+/// ```
+#[macro_export]
+macro_rules! assert_key {
+    ($map:expr, $key:expr, value == $val:expr) => {
+        match $map.get($key) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value != *expected {
+                        $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                            format_args!("`value == {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                                $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                            ],
+                        ).source("==", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                    format_args!("`value == {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                    &[
+                        $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                    ],
+                ).source("==", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($map), stringify!($key))));
+            }
+        }
+    };
+    ($map:expr, $key:expr, value != $val:expr) => {
+        match $map.get($key) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value == *expected {
+                        $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                            format_args!("`value != {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                                $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                            ],
+                        ).source("!=", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                    format_args!("`value != {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                    &[
+                        $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                    ],
+                ).source("!=", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($map), stringify!($key))));
+            }
+        }
+    };
+    ($map:expr, $key:expr, value < $val:expr) => {
+        match $map.get($key) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value >= *expected {
+                        $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                            format_args!("`value < {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                                $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                            ],
+                        ).source("<", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                    format_args!("`value < {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                    &[
+                        $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                    ],
+                ).source("<", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($map), stringify!($key))));
+            }
+        }
+    };
+    ($map:expr, $key:expr, value <= $val:expr) => {
+        match $map.get($key) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value > *expected {
+                        $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                            format_args!("`value <= {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                                $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                            ],
+                        ).source("<=", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                    format_args!("`value <= {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                    &[
+                        $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                    ],
+                ).source("<=", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($map), stringify!($key))));
+            }
+        }
+    };
+    ($map:expr, $key:expr, value > $val:expr) => {
+        match $map.get($key) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value <= *expected {
+                        $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                            format_args!("`value > {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                                $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                            ],
+                        ).source(">", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                    format_args!("`value > {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                    &[
+                        $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                    ],
+                ).source(">", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($map), stringify!($key))));
+            }
+        }
+    };
+    ($map:expr, $key:expr, value >= $val:expr) => {
+        match $map.get($key) {
+            Some(value) => match (&$val) {
+                expected => {
+                    if value < *expected {
+                        $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                            format_args!("`value >= {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                            &[
+                                $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&value))),
+                                $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(expected))),
+                                $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                            ],
+                        ).source(">=", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&value, expected))))
+                    }
+                }
+            },
+            None => {
+                $crate::failure::fail("assert_key", &$crate::report::Report::new(
+                    format_args!("`value >= {}` where `value = {}[{}]`", stringify!($val), stringify!($map), stringify!($key)),
+                    &[
+                        $crate::report::Field::other(format_args!("key"), format_args!("{:?}", $key)),
+                    ],
+                ).source(">=", &[concat!(stringify!($map), "[", stringify!($key), "]"), stringify!($val)]).details(format_args!(" `{}[{}]` does not exist", stringify!($map), stringify!($key))));
+            }
+        }
+    };
+}
+
+/// Asserts that two maps hold the same entries, reporting missing, extra and differing keys.
+///
+/// ### Parameters
+///
+/// - `&actual` A reference to the map under test.
+/// - `&expected` A reference to the map it should equal.
+/// - `description` ***(optional)*** A format string describing the assertion.
+///
+/// ### Dependencies
+///
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - Both maps must be a [HashMap](https://doc.rust-lang.org/std/collections/struct.HashMap.html) or [BTreeMap](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html) with the same key type.
+/// - The values of `actual` must implement PartialEq for the values of `expected`.
+///
+/// ### Example
+///
+/// ```
+/// use std::collections::{BTreeMap, HashMap};
+/// use totems::assert_map_eq;
+/// let hash: HashMap<_, _> = vec![(1, "one"), (2, "two")].into_iter().collect();
+/// let tree: BTreeMap<_, _> = vec![(1, "one"), (2, "two")].into_iter().collect();
+/// assert_map_eq!(&hash, &tree);
+/// ```
+///
+/// ### Example Error Messages
+///
+/// Lines starting with `-` come from `actual`, lines starting with `+` come from `expected`.
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `&hash == &tree`
+///  missing keys:
+/// +[3]: "three"
+///  extra keys:
+/// -[4]: "four"
+///  differing values:
+/// -[2]: "two"
+/// +[2]: "deux"'
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_map_eq {
+    ($actual:expr, $expected:expr) => {
+        if let Some(diff) = $crate::collections::map_diff($actual, $expected) {
+            $crate::failure::fail("assert_map_eq", &$crate::report::Report::new(
+                format_args!("`{} == {}`", stringify!($actual), stringify!($expected)),
+                &[],
+            ).source("==", &[stringify!($actual), stringify!($expected)]).details(format_args!("{}", diff)));
+        }
+    };
+    ($actual:expr, $expected:expr,) => {
+        $crate::assert_map_eq!($actual, $expected)
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        if let Some(diff) = $crate::collections::map_diff($actual, $expected) {
+            $crate::failure::fail("assert_map_eq", &$crate::report::Report::new(
+                format_args!("`{} == {}`", stringify!($actual), stringify!($expected)),
+                &[],
+            ).source("==", &[stringify!($actual), stringify!($expected)]).details(format_args!("{}", diff)).message(format_args!($($arg)+)));
+        }
+    };
+}
+
+/// Asserts that the number of `items` in a `collection` matching a `predicate` has a relationship to some count.
+///
+/// ### Parameters
+///
+/// - `&collection` A reference to a collection.
+/// - `predicate` A closure or function that takes an `item` and returns a boolean.
+/// - `count` The number of matches to compare against, preceded by `==`, `!=`, `<`, `<=`, `>` or `>=`.
+/// - `description` ***(optional)*** A string describing the predicate.
+///
+/// ### Dependencies
+///
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `&collection` must implement [IntoIterator](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html),
+///   and its items must implement [Clone](https://doc.rust-lang.org/std/clone/trait.Clone.html), as references do.
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_count;
+/// let vec: Vec<Result<u32, &str>> = vec![Ok(1), Err("bad"), Ok(3), Err("worse")];
+/// assert_count!(&vec, |x| x.is_err(), == 2);
+/// assert_count!(&vec, |x| x.is_err(), != 3);
+/// assert_count!(&vec, |x| x.is_err(), <  3);
+/// assert_count!(&vec, |x| x.is_err(), <= 2);
+/// assert_count!(&vec, |x| x.is_err(), >  1);
+/// assert_count!(&vec, |x| x.is_err(), >= 2, "is_err");
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `count == 1` for items of `&vec` matching `|x| x.is_err()`: is_err
+///   left: 2
+///  right: 1
+///  [1]: Err("bad")
+///  [3]: Err("worse")'
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_count {
+    ($collection:expr, $predicate:expr, == $count:expr) => {
+        $crate::assert_count!(@check $collection, $predicate, ==, $count, [])
+    };
+    ($collection:expr, $predicate:expr, == $count:expr, $($arg:tt)+) => {
+        $crate::assert_count!(@check $collection, $predicate, ==, $count, [$($arg)+])
+    };
+    ($collection:expr, $predicate:expr, != $count:expr) => {
+        $crate::assert_count!(@check $collection, $predicate, !=, $count, [])
+    };
+    ($collection:expr, $predicate:expr, != $count:expr, $($arg:tt)+) => {
+        $crate::assert_count!(@check $collection, $predicate, !=, $count, [$($arg)+])
+    };
+    ($collection:expr, $predicate:expr, < $count:expr) => {
+        $crate::assert_count!(@check $collection, $predicate, <, $count, [])
+    };
+    ($collection:expr, $predicate:expr, < $count:expr, $($arg:tt)+) => {
+        $crate::assert_count!(@check $collection, $predicate, <, $count, [$($arg)+])
+    };
+    ($collection:expr, $predicate:expr, <= $count:expr) => {
+        $crate::assert_count!(@check $collection, $predicate, <=, $count, [])
+    };
+    ($collection:expr, $predicate:expr, <= $count:expr, $($arg:tt)+) => {
+        $crate::assert_count!(@check $collection, $predicate, <=, $count, [$($arg)+])
+    };
+    ($collection:expr, $predicate:expr, > $count:expr) => {
+        $crate::assert_count!(@check $collection, $predicate, >, $count, [])
+    };
+    ($collection:expr, $predicate:expr, > $count:expr, $($arg:tt)+) => {
+        $crate::assert_count!(@check $collection, $predicate, >, $count, [$($arg)+])
+    };
+    ($collection:expr, $predicate:expr, >= $count:expr) => {
+        $crate::assert_count!(@check $collection, $predicate, >=, $count, [])
+    };
+    ($collection:expr, $predicate:expr, >= $count:expr, $($arg:tt)+) => {
+        $crate::assert_count!(@check $collection, $predicate, >=, $count, [$($arg)+])
+    };
+    (@check $collection:expr, $predicate:expr, $op:tt, $count:expr, [$($arg:tt)*]) => {
+        match $crate::collections::matching($collection, $predicate) {
+            $crate::collections::Scan::Passed(matches) => {
+                if !(matches.count $op $count) {
+                    $crate::assert_count!(@fail $crate::report::Report::new(
+                        format_args!("`count {} {}` for items of `{}` matching `{}`",
+                            stringify!($op), stringify!($count), stringify!($collection), stringify!($predicate)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{}", matches.count)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $count)),
+                        ],
+                    ).source(stringify!($op), &[stringify!($collection), stringify!($predicate), stringify!($count)]).details(format_args!("{}", matches)), [$($arg)*])
+                }
+            }
+            scan => {
+                $crate::assert_count!(@fail $crate::report::Report::new(
+                    format_args!("`count {} {}` for items of `{}` matching `{}`",
+                        stringify!($op), stringify!($count), stringify!($collection), stringify!($predicate)),
+                    &[],
+                ).source(stringify!($op), &[stringify!($collection), stringify!($predicate), stringify!($count)]).details(format_args!("{}", scan)), [$($arg)*])
+            }
+        }
+    };
+    (@fail $report:expr, []) => {
+        $crate::failure::fail("assert_count", &$report)
+    };
+    (@fail $report:expr, [$($arg:tt)+]) => {
+        $crate::failure::fail("assert_count", &$report.message(format_args!($($arg)+)))
+    };
+}
+
+/// Asserts that *exactly one* `item` in a `collection` matches a `predicate`.
+///
+/// ### Parameters
+///
+/// - `&collection` A reference to a collection.
+/// - `predicate` A closure or function that takes an `item` and returns a boolean.
+/// - `description` ***(optional)*** A string describing the predicate.
+///
+/// ### Dependencies
+///
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `&collection` must implement [IntoIterator](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_exactly_one;
+/// let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+/// assert_exactly_one!(&vec, |&x| x > 17, "> 17");
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `count == 1` for items of `&vec` matching `|&x| x > 15`: > 15
+///   left: 2
+///  right: 1
+///  [8]: 17
+///  [9]: 19'
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_exactly_one {
+    ($collection:expr, $predicate:expr) => {
+        $crate::assert_count!($collection, $predicate, == 1)
+    };
+    ($collection:expr, $predicate:expr, $($arg:tt)+) => {
+        $crate::assert_count!($collection, $predicate, == 1, $($arg)+)
+    };
+}
+
+/// Asserts that *no* `item` in a `collection` matches a `predicate`.
+///
+/// ### Parameters
+///
+/// - `&collection` A reference to a collection.
+/// - `predicate` A closure or function that takes an `item` and returns a boolean.
+/// - `description` ***(optional)*** A string describing the predicate.
+///
+/// ### Dependencies
+///
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `&collection` must implement [IntoIterator](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_none_match;
+/// let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+/// assert_none_match!(&vec, |&x| x % 2 == 0, "even");
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `count == 0` for items of `&vec` matching `|&x| x > 15`: > 15
+///   left: 2
+///  right: 0
+///  [8]: 17
+///  [9]: 19'
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_none_match {
+    ($collection:expr, $predicate:expr) => {
+        $crate::assert_count!($collection, $predicate, == 0)
+    };
+    ($collection:expr, $predicate:expr, $($arg:tt)+) => {
+        $crate::assert_count!($collection, $predicate, == 0, $($arg)+)
+    };
+}
+
+/// Asserts that two collections of floating-point numbers are approximately equal element by element.
+///
+/// ### Parameters
+///
+/// - `&actual` A reference to the collection under test.
+/// - `&expected` A reference to the collection it should equal.
+/// - `abs = tolerance`, `rel = tolerance` or `ulps = tolerance` The tolerance for each element,
+///   as in [assert_approx_eq](macro.assert_approx_eq.html).
+/// - `description` ***(optional)*** A format string describing the assertion.
+///
+/// ### Dependencies
+///
+/// - `&actual` and `&expected` must implement [IntoIterator](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html)
+///   over [f32](https://doc.rust-lang.org/std/primitive.f32.html) or [f64](https://doc.rust-lang.org/std/primitive.f64.html) values or references.
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_all_approx_eq;
+/// let actual = vec![0.1 + 0.2, 0.3 + 0.4, 1.0 / 3.0];
+/// let expected = [0.3, 0.7, 0.333_333_333];
+/// assert_all_approx_eq!(&actual, &expected, abs = 1e-6);
+/// assert_all_approx_eq!(&actual, &expected, rel = 1e-6);
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'main' panicked at 'assertion failed: `&actual ≈ &expected`
+///  outside tolerance: 2 of 4 elements
+///  tolerance: 1e-6 (absolute)
+///  max absolute error: 0.5 at [3]
+///  mean absolute error: 0.125025
+///  [1]: actual 0.7, expected 0.7001, difference 0.00009999999999998899
+///  [3]: actual 2.0, expected 2.5, difference 0.5'
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_all_approx_eq {
+    ($actual:expr, $expected:expr, abs = $tolerance:expr) => {
+        $crate::assert_all_approx_eq!(@check $actual, $expected, $crate::inequalities::Tolerance::Abs($tolerance), [])
+    };
+    ($actual:expr, $expected:expr, abs = $tolerance:expr, $($arg:tt)+) => {
+        $crate::assert_all_approx_eq!(@check $actual, $expected, $crate::inequalities::Tolerance::Abs($tolerance), [$($arg)+])
+    };
+    ($actual:expr, $expected:expr, rel = $tolerance:expr) => {
+        $crate::assert_all_approx_eq!(@check $actual, $expected, $crate::inequalities::Tolerance::Rel($tolerance), [])
+    };
+    ($actual:expr, $expected:expr, rel = $tolerance:expr, $($arg:tt)+) => {
+        $crate::assert_all_approx_eq!(@check $actual, $expected, $crate::inequalities::Tolerance::Rel($tolerance), [$($arg)+])
+    };
+    ($actual:expr, $expected:expr, ulps = $tolerance:expr) => {
+        $crate::assert_all_approx_eq!(@check $actual, $expected, $crate::inequalities::Tolerance::Ulps($tolerance), [])
+    };
+    ($actual:expr, $expected:expr, ulps = $tolerance:expr, $($arg:tt)+) => {
+        $crate::assert_all_approx_eq!(@check $actual, $expected, $crate::inequalities::Tolerance::Ulps($tolerance), [$($arg)+])
+    };
+    (@check $actual:expr, $expected:expr, $tolerance:expr, []) => {
+        if let Some(diff) = $crate::collections::approx_diff($actual, $expected, $tolerance) {
+            $crate::failure::fail("assert_all_approx_eq", &$crate::report::Report::new(
+                format_args!("`{} ≈ {}`", stringify!($actual), stringify!($expected)),
+                &[],
+            ).source("≈", &[stringify!($actual), stringify!($expected)]).details(format_args!("{}", diff)));
+        }
+    };
+    (@check $actual:expr, $expected:expr, $tolerance:expr, [$($arg:tt)+]) => {
+        if let Some(diff) = $crate::collections::approx_diff($actual, $expected, $tolerance) {
+            $crate::failure::fail("assert_all_approx_eq", &$crate::report::Report::new(
+                format_args!("`{} ≈ {}`", stringify!($actual), stringify!($expected)),
+                &[],
+            ).source("≈", &[stringify!($actual), stringify!($expected)]).details(format_args!("{}", diff)).message(format_args!($($arg)+)));
+        }
+    };
+}
+
+//=============================================================================================
+// Limits
+//=============================================================================================
+
+/// The number of elements of a collection scanned by default before a collection macro gives up.
+pub const DEFAULT_SCAN_LIMIT: usize = 10_000_000;
+
+/// The number of elements printed by default before a collection is truncated in a failure message.
+pub const DEFAULT_DISPLAY_LIMIT: usize = 20;
+
+static SCAN_LIMIT: AtomicUsize = AtomicUsize::new(DEFAULT_SCAN_LIMIT);
+static DISPLAY_LIMIT: AtomicUsize = AtomicUsize::new(DEFAULT_DISPLAY_LIMIT);
+
+/// Sets how many elements of a collection the collection macros will scan before failing.
+///
+/// This keeps `assert_all!`, `assert_contains!` and friends from hanging on infinite iterators,
+/// and on iterators like `0..u64::MAX` whose upper bound on their length is just as far out of
+/// reach: a scan that reaches the limit without an answer fails with a message saying so.
+/// Collections within the limit are always scanned to the end. The limit is shared by every
+/// thread in the process.
+///
+/// ### Example
+///
+/// ```
+/// use totems::collections::set_scan_limit;
+/// use totems::assert_any;
+/// set_scan_limit(1_000);
+/// assert_any!(1.., |x| x == 500);
+/// ```
+///
+/// ```should_panic
+/// use totems::collections::set_scan_limit;
+/// use totems::assert_any;
+/// set_scan_limit(1_000);
+/// assert_any!(1.., |x| x == 5_000); // panics: stopped after 1,000 elements
+/// ```
+pub fn set_scan_limit(limit: usize) {
+    SCAN_LIMIT.store(limit, Ordering::Relaxed);
+}
+
+/// Returns the number of elements of a collection the collection macros will scan before failing.
+pub fn scan_limit() -> usize {
+    SCAN_LIMIT.load(Ordering::Relaxed)
+}
+
+/// Sets how many elements of a collection are printed in a failure message before it is truncated.
+///
+/// The limit is shared by every thread in the process.
+///
+/// ### Example
+///
+/// ```
+/// use totems::collections::{set_display_limit, Truncated};
+/// set_display_limit(3);
+/// assert_eq!(format!("{:?}", Truncated(1..=10)), "[1, 2, 3, … 7 more]");
+/// ```
+pub fn set_display_limit(limit: usize) {
+    DISPLAY_LIMIT.store(limit, Ordering::Relaxed);
+}
+
+/// Returns how many elements of a collection are printed in a failure message before it is truncated.
+pub fn display_limit() -> usize {
+    DISPLAY_LIMIT.load(Ordering::Relaxed)
+}
+
+//=============================================================================================
+// Helpers
+//=============================================================================================
+
+/// Renders a collection like a list, printing at most [display_limit](fn.display_limit.html) elements.
+///
+/// The remaining elements are counted, up to the [scan_limit](fn.scan_limit.html), and
+/// summarized:
+///
+/// ```text
+/// [1, 3, 5, … 999,995 more]
+/// ```
+#[doc(hidden)]
+pub struct Truncated<C>(pub C);
+
+impl<C> fmt::Debug for Truncated<C>
+where
+    C: IntoIterator + Clone,
+    C::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self.0.clone().into_iter();
+        let mut list = f.debug_list();
+        list.entries(iter.by_ref().take(display_limit()));
+        match remaining(iter) {
+            Remaining::None => {}
+            more => { list.entry(&more); }
+        }
+        list.finish()
+    }
+}
+
+/// Renders a map like [Truncated](struct.Truncated.html) renders a list.
+#[doc(hidden)]
+pub struct TruncatedMap<M>(pub M);
+
+impl<M, K, V> fmt::Debug for TruncatedMap<M>
+where
+    M: IntoIterator<Item = (K, V)> + Clone,
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self.0.clone().into_iter();
+        f.write_str("{")?;
+        for (i, (key, value)) in iter.by_ref().take(display_limit()).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}: {:?}", key, value)?;
+        }
+        match (remaining(iter), display_limit()) {
+            (Remaining::None, _) => {}
+            (more, 0) => write!(f, "{:?}", more)?,
+            (more, _) => write!(f, ", {:?}", more)?,
+        }
+        f.write_str("}")
+    }
+}
+
+/// The number of elements left over after a truncated rendering.
+#[derive(PartialEq)]
+enum Remaining {
+    None,
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+fn remaining<I: Iterator>(mut iter: I) -> Remaining {
+    let limit = scan_limit();
+    let count = iter.by_ref().take(limit).count();
+    if count == 0 {
+        Remaining::None
+    } else if count == limit && iter.next().is_some() {
+        Remaining::AtLeast(count)
+    } else {
+        Remaining::Exactly(count)
+    }
+}
+
+impl fmt::Debug for Remaining {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Remaining::None => Ok(()),
+            Remaining::Exactly(count) => write!(f, "… {} more", Grouped(count)),
+            Remaining::AtLeast(count) => write!(f, "… at least {} more", Grouped(count)),
+        }
+    }
+}
+
+/// Displays a number with thousands separators, e.g. `999,995`.
+struct Grouped(usize);
+
+impl fmt::Display for Grouped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            0..=999 => write!(f, "{}", self.0),
+            n => write!(f, "{},{:03}", Grouped(n / 1000), n % 1000),
+        }
+    }
+}
+
+/// The outcome of scanning a collection within the [scan_limit](fn.scan_limit.html).
+///
+/// Its `Display` implementation explains why a scan stopped early, and is empty otherwise.
+#[doc(hidden)]
+#[derive(Debug, PartialEq)]
+pub enum Scan<T> {
+    Passed(T),
+    Failed,
+    LimitReached(usize),
+}
+
+impl<T> fmt::Display for Scan<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Scan::LimitReached(limit) => writeln!(f, " scan limit: stopped after {} elements", Grouped(limit)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks that every item of `collection` matches `predicate`.
+#[doc(hidden)]
+pub fn scan_all<C, P>(collection: C, mut predicate: P) -> Scan<()>
+where
+    C: IntoIterator,
+    P: FnMut(C::Item) -> bool,
+{
+    let mut iter = collection.into_iter();
+    let limit = scan_limit();
+    for item in iter.by_ref().take(limit) {
+        if !predicate(item) {
+            return Scan::Failed;
+        }
+    }
+    match iter.next() {
+        Some(_) => Scan::LimitReached(limit),
+        None => Scan::Passed(()),
+    }
+}
+
+/// Checks that some item of `collection` matches `predicate`.
+#[doc(hidden)]
+pub fn scan_any<C, P>(collection: C, mut predicate: P) -> Scan<()>
+where
+    C: IntoIterator,
+    P: FnMut(C::Item) -> bool,
+{
+    let mut iter = collection.into_iter();
+    let limit = scan_limit();
+    for item in iter.by_ref().take(limit) {
+        if predicate(item) {
+            return Scan::Passed(());
+        }
+    }
+    match iter.next() {
+        Some(_) => Scan::LimitReached(limit),
+        None => Scan::Failed,
+    }
+}
+
+/// Returns the first item of `collection` that matches `predicate`.
+#[doc(hidden)]
+pub fn find<C, P>(collection: C, mut predicate: P) -> Scan<C::Item>
+where
+    C: IntoIterator,
+    P: FnMut(&C::Item) -> bool,
+{
+    let mut iter = collection.into_iter();
+    let limit = scan_limit();
+    for item in iter.by_ref().take(limit) {
+        if predicate(&item) {
+            return Scan::Passed(item);
+        }
+    }
+    match iter.next() {
+        Some(_) => Scan::LimitReached(limit),
+        None => Scan::Failed,
+    }
+}
+
+/// Diffs `actual` against `expected` and renders the elements that were removed or added.
+///
+/// Elements of the longest common subsequence stay aligned and are left out, so an insertion or
+/// a deletion shows as a single `+` or `-` line. Removed elements are numbered by their position
+/// in `actual`, and added elements by their position in `expected`. Returns `None` when both
+/// sequences are equal. At most [display_limit](fn.display_limit.html) differences are rendered.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn seq_diff<A, E>(actual: A, expected: E) -> Option<String>
+where
+    A: IntoIterator,
+    E: IntoIterator,
+    A::Item: PartialEq<E::Item> + fmt::Debug,
+    E::Item: fmt::Debug,
+{
+    let shown = display_limit();
+    let mut actual = actual.into_iter();
+    let mut expected = expected.into_iter();
+    let limit = scan_limit();
+    let actual_items: Vec<A::Item> = actual.by_ref().take(limit).collect();
+    let expected_items: Vec<E::Item> = expected.by_ref().take(limit).collect();
+    let scan = match (actual.next(), expected.next()) {
+        (None, None) => Scan::Passed(()),
+        _ => Scan::LimitReached(limit),
+    };
+
+    let edits = seq_edits(&actual_items, &expected_items);
+    if edits.is_empty() && scan == Scan::Passed(()) {
+        return None;
+    }
+
+    let mut diff = scan.to_string();
+    if actual_items.len() != expected_items.len() {
+        let _ = writeln!(diff, " length: actual has {} elements, expected has {}", actual_items.len(), expected_items.len());
+    }
+    for &(sign, index) in edits.iter().take(shown) {
+        let _ = match sign {
+            '-' => writeln!(diff, "-[{}]: {:?}", index, actual_items[index]),
+            _ => writeln!(diff, "+[{}]: {:?}", index, expected_items[index]),
+        };
+    }
+    if edits.len() > shown {
+        let _ = writeln!(diff, " … {} more differences", Grouped(edits.len() - shown));
+    }
+    Some(diff)
+}
+
+/// Pairs the position of every element of `actual` missing from `expected` with `-`, and of every
+/// element of `expected` missing from `actual` with `+`, keeping the longest common subsequence
+/// in place.
+///
+/// The common prefix and suffix are skipped first. What remains is diffed as entirely removed and
+/// added when it is too large for the quadratic table.
+#[cfg(feature = "alloc")]
+fn seq_edits<A: PartialEq<E>, E>(actual: &[A], expected: &[E]) -> Vec<(char, usize)> {
+    const MAX_CELLS: usize = 1 << 22;
+
+    let prefix = actual.iter().zip(expected).take_while(|(a, e)| a == e).count();
+    let suffix = actual[prefix..]
+        .iter()
+        .rev()
+        .zip(expected[prefix..].iter().rev())
+        .take_while(|(a, e)| a == e)
+        .count();
+    let actual = &actual[prefix..actual.len() - suffix];
+    let expected = &expected[prefix..expected.len() - suffix];
+
+    let (n, m) = (actual.len(), expected.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_CELLS {
+        let removed = (0..n).map(|i| ('-', prefix + i));
+        return removed.chain((0..m).map(|j| ('+', prefix + j))).collect();
+    }
+    // lengths[i][j] is the length of the longest common subsequence of actual[i..] and expected[j..].
+    let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+    let at = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[at(i, j)] = match actual[i] == expected[j] {
+                true => lengths[at(i + 1, j + 1)] + 1,
+                false => lengths[at(i + 1, j)].max(lengths[at(i, j + 1)]),
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut edits = Vec::new();
+    while i < n || j < m {
+        if i < n && j < m && actual[i] == expected[j] {
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lengths[at(i + 1, j)] >= lengths[at(i, j + 1)]) {
+            edits.push(('-', prefix + i));
+            i += 1;
+        } else {
+            edits.push(('+', prefix + j));
+            j += 1;
+        }
+    }
+    edits
+}
+
+/// A floating-point item of a collection, by value or by reference.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub trait FloatItem {
+    type Float: Float;
+
+    fn float(self) -> Self::Float;
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_float_item {
+    ($float:ty) => {
+        impl FloatItem for $float {
+            type Float = $float;
+
+            fn float(self) -> $float {
+                self
+            }
+        }
+
+        impl<'a> FloatItem for &'a $float {
+            type Float = $float;
+
+            fn float(self) -> $float {
+                *self
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_float_item!(f32);
+#[cfg(feature = "alloc")]
+impl_float_item!(f64);
+
+/// Walks `actual` and `expected` together and renders the elements that are not within `tolerance`.
+///
+/// Returns `None` when both sequences have the same length and all elements are approximately equal.
+/// At most [display_limit](fn.display_limit.html) elements are rendered, after a summary of the errors.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn approx_diff<A, E>(actual: A, expected: E, tolerance: Tolerance<<A::Item as FloatItem>::Float>) -> Option<String>
+where
+    A: IntoIterator,
+    E: IntoIterator,
+    A::Item: FloatItem,
+    E::Item: FloatItem<Float = <A::Item as FloatItem>::Float>,
+{
+    let shown = display_limit();
+    let mut actual = actual.into_iter();
+    let mut expected = expected.into_iter();
+    let limit = scan_limit();
+    let mut lines = String::new();
+    let mut outside = 0;
+    let mut actual_len = 0;
+    let mut expected_len = 0;
+    let mut scan = Scan::Passed(());
+    let mut max_error: Option<(f64, usize)> = None;
+    let mut total_error = 0.0;
+    let mut paired = 0;
+
+    for index in 0.. {
+        if index == limit {
+            scan = Scan::LimitReached(limit);
+            break;
+        }
+        let (a, e) = match (actual.next(), expected.next()) {
+            (None, None) => break,
+            (a, e) => (a.map(FloatItem::float), e.map(FloatItem::float)),
+        };
+        actual_len += a.is_some() as usize;
+        expected_len += e.is_some() as usize;
+        let line = match (a, e) {
+            (Some(a), Some(e)) => {
+                let error = a.abs_diff(e).to_f64();
+                if !error.is_nan() {
+                    paired += 1;
+                    total_error += error;
+                    match max_error {
+                        Some((max, _)) if max >= error => {}
+                        _ => max_error = Some((error, index)),
+                    }
+                }
+                match approx_eq(a, e, tolerance) {
+                    Some(mismatch) => format!(" [{}]: actual {:?}, expected {:?}, {}", index, a, e, mismatch.brief()),
+                    None => continue,
+                }
+            }
+            (Some(a), None) => format!("-[{}]: {:?}", index, a),
+            (None, Some(e)) => format!("+[{}]: {:?}", index, e),
+            (None, None) => unreachable!(),
+        };
+        outside += 1;
+        if outside <= shown {
+            lines.push_str(&line);
+            lines.push('\n');
+        }
+    }
+
+    if outside == 0 && scan == Scan::Passed(()) {
+        return None;
+    }
+
+    let mut diff = scan.to_string();
+    if actual_len != expected_len {
+        let _ = writeln!(diff, " length: actual has {} elements, expected has {}", actual_len, expected_len);
+    }
+    let _ = writeln!(diff, " outside tolerance: {} of {} elements", Grouped(outside), Grouped(actual_len.max(expected_len)));
+    let _ = writeln!(diff, " tolerance: {} ({})", tolerance, tolerance.kind());
+    if let Some((max, index)) = max_error {
+        let _ = writeln!(diff, " max absolute error: {:?} at [{}]", max, index);
+        let _ = writeln!(diff, " mean absolute error: {:?}", total_error / paired as f64);
+    }
+    diff.push_str(&lines);
+    if outside > shown {
+        let _ = writeln!(diff, " … {} more", Grouped(outside - shown));
+    }
+    Some(diff)
+}
+
+/// Applies `predicate` to a borrowed `item`, pinning down the closure's argument type.
+#[doc(hidden)]
+pub fn matches<T, P>(item: &T, predicate: P) -> bool
+where
+    P: FnOnce(&T) -> bool,
+{
+    predicate(item)
+}
+
+/// Returns the `position`th item of `collection` counting from the end, where `1` is the last item.
+#[doc(hidden)]
+pub fn nth_back<C>(collection: C, position: usize) -> Option<C::Item>
+where
+    C: IntoIterator,
+    C::IntoIter: DoubleEndedIterator,
+{
+    position.checked_sub(1).and_then(|n| collection.into_iter().rev().nth(n))
+}
+
+/// The map operations needed by [map_diff](fn.map_diff.html).
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub trait Map {
+    type Key;
+    type Value;
+
+    fn entries<'a>(&'a self) -> Box<dyn Iterator<Item = (&'a Self::Key, &'a Self::Value)> + 'a>;
+    fn lookup(&self, key: &Self::Key) -> Option<&Self::Value>;
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Map for std::collections::HashMap<K, V, S>
+where
+    K: Eq + std::hash::Hash,
+    S: std::hash::BuildHasher,
+{
+    type Key = K;
+    type Value = V;
+
+    fn entries<'a>(&'a self) -> Box<dyn Iterator<Item = (&'a K, &'a V)> + 'a> {
+        Box::new(self.iter())
+    }
+
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Map for alloc::collections::BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn entries<'a>(&'a self) -> Box<dyn Iterator<Item = (&'a K, &'a V)> + 'a> {
+        Box::new(self.iter())
+    }
+
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+/// Compares two maps and renders the keys that are missing, extra, or hold different values.
+///
+/// Returns `None` when both maps hold the same entries. Each section is sorted by the rendered
+/// keys, so the output and the keys that fit within the [display_limit](fn.display_limit.html)
+/// do not depend on the iteration order of the maps.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn map_diff<A, E>(actual: &A, expected: &E) -> Option<String>
+where
+    A: Map,
+    E: Map<Key = A::Key>,
+    A::Key: fmt::Debug,
+    A::Value: PartialEq<E::Value> + fmt::Debug,
+    E::Value: fmt::Debug,
+{
+    let mut missing = Section::new(" missing keys:");
+    let mut extra = Section::new(" extra keys:");
+    let mut differing = Section::new(" differing values:");
+
+    for (key, e) in expected.entries() {
+        match actual.lookup(key) {
+            Some(a) => {
+                if a != e {
+                    let key = format!("{:?}", key);
+                    let entry = format!("-[{}]: {:?}\n+[{}]: {:?}", key, a, key, e);
+                    differing.push(key, entry);
+                }
+            }
+            None => {
+                let key = format!("{:?}", key);
+                let entry = format!("+[{}]: {:?}", key, e);
+                missing.push(key, entry);
+            }
+        }
+    }
+    for (key, a) in actual.entries() {
+        if expected.lookup(key).is_none() {
+            let key = format!("{:?}", key);
+            let entry = format!("-[{}]: {:?}", key, a);
+            extra.push(key, entry);
+        }
+    }
+
+    if missing.entries.is_empty() && extra.entries.is_empty() && differing.entries.is_empty() {
+        return None;
+    }
+    Some(format!("{}{}{}", missing, extra, differing))
+}
+
+/// A titled list of diff entries, rendered in the order of their keys, that stops rendering after
+/// [display_limit](fn.display_limit.html) entries.
+#[cfg(feature = "alloc")]
+struct Section {
+    title: &'static str,
+    entries: Vec<(String, String)>,
+}
+
+#[cfg(feature = "alloc")]
+impl Section {
+    fn new(title: &'static str) -> Section {
+        Section { title, entries: Vec::new() }
+    }
+
+    fn push(&mut self, key: String, entry: String) {
+        self.entries.push((key, entry));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.entries.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "{}", self.title)?;
+        let mut sorted: Vec<&(String, String)> = self.entries.iter().collect();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+        let shown = display_limit();
+        for (_, entry) in sorted.iter().take(shown) {
+            writeln!(f, "{}", entry)?;
+        }
+        if sorted.len() > shown {
+            writeln!(f, " … {} more", Grouped(sorted.len() - shown))?;
+        }
+        Ok(())
+    }
+}
+
+/// The items of a collection that match a predicate, as found by [matching](fn.matching.html).
+///
+/// Its `Display` implementation renders the first [display_limit](fn.display_limit.html) of them
+/// with their positions, one per line.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[derive(Debug, PartialEq)]
+pub struct Matches<T> {
+    pub count: usize,
+    shown: Vec<(usize, T)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: fmt::Debug> fmt::Display for Matches<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, item) in &self.shown {
+            writeln!(f, " [{}]: {:?}", index, item)?;
+        }
+        match self.count - self.shown.len() {
+            0 => Ok(()),
+            more => writeln!(f, " … {} more", Grouped(more)),
+        }
+    }
+}
+
+/// Counts the items in `collection` that match `predicate`, keeping the first
+/// [display_limit](fn.display_limit.html) of them to render.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn matching<C, P>(collection: C, mut predicate: P) -> Scan<Matches<C::Item>>
+where
+    C: IntoIterator,
+    C::Item: Clone,
+    P: FnMut(C::Item) -> bool,
+{
+    let shown = display_limit();
+    let mut iter = collection.into_iter();
+    let limit = scan_limit();
+    let mut matches = Matches { count: 0, shown: Vec::new() };
+    for (index, item) in iter.by_ref().take(limit).enumerate() {
+        let kept = (matches.shown.len() < shown).then(|| item.clone());
+        if predicate(item) {
+            matches.count += 1;
+            matches.shown.extend(kept.map(|item| (index, item)));
+        }
+    }
+    match iter.next() {
+        Some(_) => Scan::LimitReached(limit),
+        None => Scan::Passed(matches),
+    }
+}
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod contains {
+    #[test]
+    fn contains_item() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 5;
+        assert_contains!(&vec, &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn excludes_item() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 2;
+        assert_contains!(&vec, &x);
+    }
+
+    #[test]
+    fn contains_match() {
+        let pairs = vec![(1, "one"), (7, "seven"), (9, "nine")];
+        let pair = assert_contains!(&pairs, where |p| p.0 == 7);
+        assert_eq!(pair, &(7, "seven"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn excludes_match() {
+        let pairs = vec![(1, "one"), (7, "seven"), (9, "nine")];
+        assert_contains!(&pairs, where |p| p.0 == 8);
+    }
+
+    #[test]
+    fn evaluated_once() {
+        use crate::assert_panics;
+        let vec = vec![1, 3, 5];
+        let (mut collections, mut items) = (0, 0);
+        let mut collection = || {
+            collections += 1;
+            &vec
+        };
+        let mut item = || {
+            items += 1;
+            &2
+        };
+        assert_panics!({ assert_contains!(collection(), item()) });
+        assert_panics!({ assert_contains!(collection(), where |&&x| x == 2) });
+        assert_eq!((collections, items), (2, 1));
+    }
+}
+
+#[cfg(test)]
+mod nth {
+    #[test]
+    fn expected_evaluated_once() {
+        use crate::assert_panics;
+        let vec = vec![1, 3, 5];
+        let mut evaluations = 0;
+        let mut expected = || {
+            evaluations += 1;
+            &6
+        };
+        assert_panics!({ assert_nth!(&vec, 2, value == expected()) });
+        assert_panics!({ assert_nth!(&vec, -1, value > expected()) });
+        assert_eq!(evaluations, 2);
+    }
+
+    #[test]
+    fn eq_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 5;
+        assert_nth!(&vec, 2, value == &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn eq_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 2, value == &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn eq_out_of_range() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 20, value == &x);
+    }
+
+    #[test]
+    fn ne_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 2, value != &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ne_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 5;
+        assert_nth!(&vec, 2, value != &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ne_out_of_range() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 20, value != &x);
+    }
+
+    #[test]
+    fn lt_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 2, value < &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lt_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 5;
         assert_nth!(&vec, 2, value < &x);
     }
 
     #[test]
     #[should_panic]
-    fn lt_incorrect() {
+    fn lt_out_of_range() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 20, value < &x);
+    }
+
+    #[test]
+    fn le_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 5;
+        assert_nth!(&vec, 2, value <= &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn le_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 4;
+        assert_nth!(&vec, 2, value <= &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn le_out_of_range() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 20, value <= &x);
+    }
+
+    #[test]
+    fn gt_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 4;
+        assert_nth!(&vec, 2, value > &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gt_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 5;
+        assert_nth!(&vec, 2, value > &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gt_out_of_range() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 20, value > &x);
+    }
+
+    #[test]
+    fn ge_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 5;
+        assert_nth!(&vec, 2, value >= &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ge_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 2, value >= &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ge_out_of_range() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        let x = 6;
+        assert_nth!(&vec, 20, value >= &x);
+    }
+}
+
+#[cfg(test)]
+mod all {
+    #[test]
+    fn all_items_match() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_all!(&vec, |&x| x > 0, "all > 0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn one_item_matches() {
+        let vec = vec![-1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_all!(&vec, |&x| x < 0, "all < 0");
+    }
+
+    #[test]
+    fn evaluated_once() {
+        use crate::assert_panics;
+        let vec = vec![1, 3, 5];
+        let mut evaluations = 0;
+        let mut collection = || {
+            evaluations += 1;
+            &vec
+        };
+        assert_panics!({ assert_all!(collection(), |&x| x < 0) });
+        assert_panics!({ assert_all!(collection(), |&x| x < 0, "all < 0") });
+        assert_eq!(evaluations, 2);
+    }
+}
+
+
+#[cfg(test)]
+mod any {
+    #[test]
+    fn all_items_match() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_any!(&vec, |&x| x > 0, "any > 0");
+    }
+
+    #[test]
+    fn one_item_matches() {
+        let vec = vec![-1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_any!(&vec, |&x| x < 0, "any < 0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_items_match() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_any!(&vec, |&x| x < 0, "any < 0");
+    }
+
+    #[test]
+    fn evaluated_once() {
+        use crate::assert_panics;
+        let vec = vec![1, 3, 5];
+        let mut evaluations = 0;
+        let mut collection = || {
+            evaluations += 1;
+            &vec
+        };
+        assert_panics!({ assert_any!(collection(), |&x| x < 0) });
+        assert_panics!({ assert_any!(collection(), |&x| x < 0, "any < 0") });
+        assert_eq!(evaluations, 2);
+    }
+}
+#[cfg(test)]
+mod seq_eq {
+    use super::seq_diff;
+
+    #[test]
+    fn equal() {
+        let vec = vec![1, 3, 5, 7, 9];
+        let arr = [1, 3, 5, 7, 9];
+        assert_seq_eq!(&vec, &arr);
+    }
+
+    #[test]
+    fn format_string() {
+        let vec = vec![1, 3, 5, 7, 9];
+        assert_seq_eq!(&vec, &vec, "{} elements", vec.len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn changed() {
+        let vec = vec![1, 3, 5, 7, 9];
+        let arr = [1, 3, 4, 7, 9];
+        assert_seq_eq!(&vec, &arr);
+    }
+
+    #[test]
+    #[should_panic]
+    fn shorter() {
+        let vec = vec![1, 3, 5, 7];
+        let arr = [1, 3, 5, 7, 9];
+        assert_seq_eq!(&vec, &arr);
+    }
+
+    #[test]
+    fn diff() {
+        let vec = vec![1, 3, 5, 7, 9];
+        let arr = [1, 3, 4, 7, 9, 11];
+        assert_eq!(
+            seq_diff(&vec, &arr).unwrap(),
+            " length: actual has 5 elements, expected has 6\n-[2]: 5\n+[2]: 4\n+[5]: 11\n",
+        );
+        assert_eq!(seq_diff(&vec, &vec), None);
+    }
+
+    #[test]
+    fn inserted_and_removed() {
+        assert_eq!(
+            seq_diff(&[1, 2, 3, 4, 5], &[0, 1, 2, 3, 4, 5]).unwrap(),
+            " length: actual has 5 elements, expected has 6\n+[0]: 0\n",
+        );
+        assert_eq!(
+            seq_diff(&[1, 2, 3, 4, 5], &[1, 2, 4, 5, 6]).unwrap(),
+            "-[2]: 3\n+[4]: 6\n",
+        );
+    }
+}
+
+#[cfg(test)]
+mod contains_key {
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn contains_key() {
+        let mut map = HashMap::new();
+        map.insert("one", 1);
+        assert_contains_key!(&map, &"one");
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_key() {
+        let mut map = BTreeMap::new();
+        map.insert("one", 1);
+        assert_contains_key!(&map, &"two");
+    }
+
+    #[test]
+    fn not_contains_key() {
+        let mut map = BTreeMap::new();
+        map.insert("one", 1);
+        assert_not_contains_key!(&map, &"two");
+    }
+
+    #[test]
+    #[should_panic]
+    fn not_contains_present_key() {
+        let mut map = HashMap::new();
+        map.insert("one", 1);
+        assert_not_contains_key!(&map, &"one");
+    }
+
+    #[test]
+    fn evaluated_once() {
+        use crate::assert_panics;
+        let map: BTreeMap<_, _> = vec![("one", 1)].into_iter().collect();
+        let mut evaluations = 0;
+        let mut lookup = || {
+            evaluations += 1;
+            (&map, &"one")
+        };
+        assert_panics!({ assert_contains_key!(lookup().0, &"two") });
+        assert_panics!({ assert_not_contains_key!(lookup().0, lookup().1) });
+        assert_eq!(evaluations, 3);
+    }
+}
+
+#[cfg(test)]
+mod key {
+    use std::collections::HashMap;
+
+    fn map() -> HashMap<&'static str, i32> {
+        vec![("five", 5)].into_iter().collect()
+    }
+
+    #[test]
+    fn expected_evaluated_once() {
+        use crate::assert_panics;
+        let mut evaluations = 0;
+        let mut expected = || {
+            evaluations += 1;
+            &4
+        };
+        assert_panics!({ assert_key!(&map(), &"five", value == expected()) });
+        assert_panics!({ assert_key!(&map(), &"five", value <= expected()) });
+        assert_eq!(evaluations, 2);
+    }
+
+    #[test]
+    fn eq_correct() {
+        assert_key!(&map(), &"five", value == &5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn eq_incorrect() {
+        assert_key!(&map(), &"five", value == &4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn eq_missing() {
+        assert_key!(&map(), &"six", value == &5);
+    }
+
+    #[test]
+    fn ne_correct() {
+        assert_key!(&map(), &"five", value != &4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ne_incorrect() {
+        assert_key!(&map(), &"five", value != &5);
+    }
+
+    #[test]
+    fn lt_correct() {
+        assert_key!(&map(), &"five", value < &6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lt_incorrect() {
+        assert_key!(&map(), &"five", value < &5);
+    }
+
+    #[test]
+    fn le_correct() {
+        assert_key!(&map(), &"five", value <= &5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn le_incorrect() {
+        assert_key!(&map(), &"five", value <= &4);
+    }
+
+    #[test]
+    fn gt_correct() {
+        assert_key!(&map(), &"five", value > &4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gt_incorrect() {
+        assert_key!(&map(), &"five", value > &5);
+    }
+
+    #[test]
+    fn ge_correct() {
+        assert_key!(&map(), &"five", value >= &5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ge_incorrect() {
+        assert_key!(&map(), &"five", value >= &6);
+    }
+}
+
+#[cfg(test)]
+mod map_eq {
+    use super::map_diff;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn equal() {
+        let hash: HashMap<_, _> = vec![(1, "one"), (2, "two")].into_iter().collect();
+        let tree: BTreeMap<_, _> = vec![(1, "one"), (2, "two")].into_iter().collect();
+        assert_map_eq!(&hash, &tree);
+        assert_map_eq!(&tree, &hash);
+    }
+
+    #[test]
+    #[should_panic]
+    fn differing() {
+        let hash: HashMap<_, _> = vec![(1, "one"), (2, "two")].into_iter().collect();
+        let tree: BTreeMap<_, _> = vec![(1, "one"), (2, "deux")].into_iter().collect();
+        assert_map_eq!(&hash, &tree, "{} entries", tree.len());
+    }
+
+    #[test]
+    fn diff() {
+        let actual: BTreeMap<_, _> = vec![(1, "one"), (2, "two"), (4, "four")].into_iter().collect();
+        let expected: BTreeMap<_, _> = vec![(1, "one"), (2, "deux"), (3, "three")].into_iter().collect();
+        assert_eq!(
+            map_diff(&actual, &expected).unwrap(),
+            " missing keys:\n+[3]: \"three\"\n extra keys:\n-[4]: \"four\"\n differing values:\n-[2]: \"two\"\n+[2]: \"deux\"\n",
+        );
+        assert_eq!(map_diff(&actual, &actual), None);
+    }
+
+    #[test]
+    fn sorted() {
+        let actual: HashMap<_, _> = (0..40).map(|i| (format!("k{:02}", i), i)).collect();
+        let expected: HashMap<_, _> = (0..40).map(|i| (format!("k{:02}", i), i + 1)).collect();
+        let diff = map_diff(&actual, &expected).unwrap();
+        let keys: Vec<_> = diff.lines().filter(|line| line.starts_with('-')).collect();
+        assert_eq!(keys.first(), Some(&"-[\"k00\"]: 0"));
+        assert_eq!(keys.last(), Some(&"-[\"k19\"]: 19"));
+        assert!(diff.ends_with(" … 20 more\n"));
+    }
+}
+
+#[cfg(test)]
+mod count {
+    use super::{matching, Scan};
+
+    fn results() -> Vec<Result<u32, &'static str>> {
+        vec![Ok(1), Err("bad"), Ok(3), Err("worse")]
+    }
+
+    #[test]
+    fn correct() {
+        let vec = results();
+        assert_count!(&vec, |x| x.is_err(), == 2);
+        assert_count!(&vec, |x| x.is_err(), != 3);
+        assert_count!(&vec, |x| x.is_err(), < 3);
+        assert_count!(&vec, |x| x.is_err(), <= 2);
+        assert_count!(&vec, |x| x.is_err(), > 1);
+        assert_count!(&vec, |x| x.is_err(), >= 2, "is_err");
+    }
+
+    #[test]
+    #[should_panic]
+    fn eq_incorrect() {
+        let vec = results();
+        assert_count!(&vec, |x| x.is_err(), == 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ge_incorrect() {
+        let vec = results();
+        assert_count!(&vec, |x| x.is_ok(), >= 3, "is_ok");
+    }
+
+    #[test]
+    fn exactly_one() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 5;
-        assert_nth!(&vec, 2, value < &x);
+        assert_exactly_one!(&vec, |&x| x > 17, "> 17");
     }
 
     #[test]
     #[should_panic]
-    fn lt_out_of_range() {
+    fn exactly_one_of_two() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
-        assert_nth!(&vec, 20, value < &x);
+        assert_exactly_one!(&vec, |&x| x > 15);
     }
 
     #[test]
-    fn le_correct() {
+    fn none_match() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 5;
-        assert_nth!(&vec, 2, value <= &x);
+        assert_none_match!(&vec, |&x| x % 2 == 0, "even");
     }
 
     #[test]
     #[should_panic]
-    fn le_incorrect() {
+    fn none_match_one() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 4;
-        assert_nth!(&vec, 2, value <= &x);
+        assert_none_match!(&vec, |&x| x == 7);
+    }
+
+    #[test]
+    fn rendered_matches() {
+        let vec = results();
+        let matches = match matching(&vec, |x| x.is_err()) {
+            Scan::Passed(matches) => matches,
+            scan => panic!("{:?}", scan),
+        };
+        assert_eq!(matches.count, 2);
+        assert_eq!(matches.to_string(), " [1]: Err(\"bad\")\n [3]: Err(\"worse\")\n");
+    }
+
+    #[test]
+    fn collection_evaluated_once() {
+        let mut evaluations = 0;
+        let mut collection = || {
+            evaluations += 1;
+            results()
+        };
+        crate::assert_panics!({ assert_count!(collection(), |x| x.is_err(), == 1) }, message contains "[3]: Err(\"worse\")");
+        assert_eq!(evaluations, 1);
+    }
+}
+
+#[cfg(test)]
+mod nth_back {
+    #[test]
+    fn eq_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_nth!(&vec, -1, value == &19);
+        assert_nth!(&vec, -10, value == &1);
     }
 
     #[test]
     #[should_panic]
-    fn le_out_of_range() {
+    fn eq_incorrect() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
-        assert_nth!(&vec, 20, value <= &x);
+        assert_nth!(&vec, -1, value == &17);
     }
 
     #[test]
-    fn gt_correct() {
+    #[should_panic]
+    fn eq_out_of_range() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 4;
-        assert_nth!(&vec, 2, value > &x);
+        assert_nth!(&vec, -11, value == &1);
     }
 
     #[test]
     #[should_panic]
-    fn gt_incorrect() {
+    fn zero() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 5;
-        assert_nth!(&vec, 2, value > &x);
+        assert_nth!(&vec, -0, value == &1);
+    }
+
+    #[test]
+    fn ne_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_nth!(&vec, -2, value != &19);
     }
 
     #[test]
     #[should_panic]
-    fn gt_out_of_range() {
+    fn ne_incorrect() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
-        assert_nth!(&vec, 20, value > &x);
+        assert_nth!(&vec, -2, value != &17);
+    }
+
+    #[test]
+    fn lt_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_nth!(&vec, -2, value < &18);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lt_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_nth!(&vec, -2, value < &17);
+    }
+
+    #[test]
+    fn le_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_nth!(&vec, -2, value <= &17);
+    }
+
+    #[test]
+    #[should_panic]
+    fn le_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_nth!(&vec, -2, value <= &16);
+    }
+
+    #[test]
+    fn gt_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_nth!(&vec, -2, value > &16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gt_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_nth!(&vec, -2, value > &17);
     }
 
     #[test]
     fn ge_correct() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 5;
-        assert_nth!(&vec, 2, value >= &x);
+        assert_nth!(&vec, -2, value >= &17);
     }
 
     #[test]
     #[should_panic]
     fn ge_incorrect() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
-        assert_nth!(&vec, 2, value >= &x);
+        assert_nth!(&vec, -2, value >= &18);
+    }
+}
+
+#[cfg(test)]
+mod slice {
+    #[test]
+    fn expected_evaluated_once() {
+        use crate::assert_panics;
+        let vec = vec![1, 3, 5];
+        let mut evaluations = 0;
+        let mut expected = || {
+            evaluations += 1;
+            &[3, 6][..]
+        };
+        assert_panics!({ assert_slice!(&vec, 1.., == expected()) });
+        assert_panics!({ assert_slice!(&vec, 1.., >= expected()) });
+        assert_eq!(evaluations, 2);
+    }
+
+    #[test]
+    fn eq_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_slice!(&vec, 2..5, == &[5, 7, 9]);
+        assert_slice!(&vec, ..2, == &vec![1, 3]);
+        assert_slice!(&vec, 10.., == &[0; 0]);
     }
 
     #[test]
     #[should_panic]
-    fn ge_out_of_range() {
+    fn eq_incorrect() {
         let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        let x = 6;
-        assert_nth!(&vec, 20, value >= &x);
+        assert_slice!(&vec, 2..5, == &[5, 7, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn eq_out_of_range() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_slice!(&vec, 8..12, == &[17, 19]);
+    }
+
+    #[test]
+    fn ne_correct() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_slice!(&vec, ..2, != &[1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ne_incorrect() {
+        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_slice!(&vec, ..2, != &[1, 3]);
+    }
+
+    #[test]
+    fn ordering_correct() {
+        let arr = [1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_slice!(&arr, 8.., < &[19][..]);
+        assert_slice!(&arr, 8.., <= &[17, 19][..]);
+        assert_slice!(&arr, 8.., > &[17][..]);
+        assert_slice!(&arr, 8.., >= &[17, 19][..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lt_incorrect() {
+        let arr = [1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_slice!(&arr, 8.., < &[17, 19][..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ge_incorrect() {
+        let arr = [1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+        assert_slice!(&arr, 8.., >= &[18][..]);
     }
 }
 
 #[cfg(test)]
-mod all {
+mod nth_predicate {
     #[test]
-    fn all_items_match() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        assert_all!(&vec, |&x| x > 0, "all > 0");
+    fn correct() {
+        let words = vec!["apple", "banana", "cherry"];
+        let word = assert_nth!(&words, 1, value => |w| w.starts_with('b'));
+        assert_eq!(word, &"banana");
+        let word = assert_nth!(&words, -1, value => |w| w.starts_with('c'));
+        assert_eq!(word, &"cherry");
     }
 
     #[test]
     #[should_panic]
-    fn one_item_matches() {
-        let vec = vec![-1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        assert_all!(&vec, |&x| x < 0, "all < 0");
+    fn incorrect() {
+        let words = vec!["apple", "banana", "cherry"];
+        assert_nth!(&words, 1, value => |w| w.starts_with('c'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn incorrect_from_end() {
+        let words = vec!["apple", "banana", "cherry"];
+        assert_nth!(&words, -3, value => |w| w.starts_with('b'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range() {
+        let words = vec!["apple", "banana", "cherry"];
+        assert_nth!(&words, 3, value => |w| w.starts_with('b'));
     }
 }
 
+#[cfg(test)]
+mod limits {
+    use super::{matching, seq_diff, Grouped, Scan, Truncated, TruncatedMap, DEFAULT_DISPLAY_LIMIT};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn grouped() {
+        assert_eq!(Grouped(0).to_string(), "0");
+        assert_eq!(Grouped(999).to_string(), "999");
+        assert_eq!(Grouped(1_000).to_string(), "1,000");
+        assert_eq!(Grouped(999_995).to_string(), "999,995");
+        assert_eq!(Grouped(12_345_678).to_string(), "12,345,678");
+    }
+
+    #[test]
+    fn truncated() {
+        assert_eq!(format!("{:?}", Truncated(&[1, 2, 3])), "[1, 2, 3]");
+        assert_eq!(
+            format!("{:?}", Truncated(1..=1_000_000)),
+            format!("{:?}", (1..=DEFAULT_DISPLAY_LIMIT).collect::<Vec<_>>()).replace("]", ", … 999,980 more]"),
+        );
+    }
+
+    #[test]
+    fn truncated_map() {
+        let map: BTreeMap<_, _> = (0..DEFAULT_DISPLAY_LIMIT + 2).map(|i| (i, i)).collect();
+        assert!(format!("{:?}", TruncatedMap(&map)).ends_with("19: 19, … 2 more}"));
+    }
+
+    #[test]
+    fn truncated_diff() {
+        let actual: Vec<_> = (0..100).collect();
+        let expected: Vec<_> = (0..100).map(|x| x + 1_000).collect();
+        let diff = seq_diff(&actual, &expected).unwrap();
+        assert_eq!(diff.lines().count(), DEFAULT_DISPLAY_LIMIT + 1);
+        assert!(diff.ends_with(" … 180 more differences\n"));
+    }
+
+    #[test]
+    fn truncated_matches() {
+        let lines = match matching(0..100, |_| true) {
+            Scan::Passed(matches) => matches.to_string(),
+            scan => panic!("{:?}", scan),
+        };
+        assert_eq!(lines.lines().count(), DEFAULT_DISPLAY_LIMIT + 1);
+        assert!(lines.ends_with(" … 80 more\n"));
+    }
+
+    #[test]
+    #[should_panic(expected = "scan limit: stopped after 10,000,000 elements")]
+    fn infinite_contains() {
+        assert_contains!(1.., 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "scan limit: stopped after 10,000,000 elements")]
+    fn infinite_all() {
+        assert_all!(1.., |x| x > 0);
+    }
+
+    #[test]
+    fn finite_within_limit() {
+        assert_all!(0..10_000_000u32, |x| x < 10_000_000);
+        assert_contains!(0..10_000_000u32, 9_999_999);
+        assert_seq_eq!(0..10_000_000u32, 0..10_000_000u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "scan limit: stopped after 10,000,000 elements")]
+    fn bounded_beyond_limit() {
+        assert_all!(0..u64::MAX, |x| x < u64::MAX);
+    }
+}
 
 #[cfg(test)]
-mod any {
+mod all_approx_eq {
+    use super::approx_diff;
+    use crate::inequalities::Tolerance;
+
     #[test]
-    fn all_items_match() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        assert_any!(&vec, |&x| x > 0, "any > 0");
+    fn correct() {
+        let actual = vec![0.1 + 0.2, 0.3 + 0.4, 1.0 / 3.0];
+        let expected = [0.3, 0.7, 0.333_333_333];
+        assert_all_approx_eq!(&actual, &expected, abs = 1e-6);
+        assert_all_approx_eq!(&actual, &expected, rel = 1e-6);
+        assert_all_approx_eq!(actual.iter().take(2), expected.iter().take(2), ulps = 4, "{} elements", 2);
     }
 
     #[test]
-    fn one_item_matches() {
-        let vec = vec![-1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        assert_any!(&vec, |&x| x < 0, "any < 0");
+    fn correct_f32() {
+        let actual: Vec<f32> = vec![0.1 + 0.2, 0.3 + 0.4];
+        assert_all_approx_eq!(actual, vec![0.3_f32, 0.7], abs = 1e-6);
     }
 
     #[test]
     #[should_panic]
-    fn no_items_match() {
-        let vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
-        assert_any!(&vec, |&x| x < 0, "any < 0");
+    fn incorrect() {
+        let actual = vec![0.1, 0.7, 1.0];
+        let expected = [0.1, 0.7001, 1.0];
+        assert_all_approx_eq!(&actual, &expected, abs = 1e-6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn shorter() {
+        let actual = vec![0.1, 0.7];
+        let expected = [0.1, 0.7, 1.0];
+        assert_all_approx_eq!(&actual, &expected, abs = 1e-6, "length");
+    }
+
+    #[test]
+    fn diff() {
+        let actual = vec![0.5, 0.75, 1.0, 2.0, f64::NAN];
+        let expected = [0.5, 0.5, 1.0, 2.5];
+        assert_eq!(
+            approx_diff(&actual, &expected, Tolerance::Abs(0.125)).unwrap(),
+            " length: actual has 5 elements, expected has 4\n \
+              outside tolerance: 3 of 5 elements\n \
+              tolerance: 0.125 (absolute)\n \
+              max absolute error: 0.5 at [3]\n \
+              mean absolute error: 0.1875\n \
+              [1]: actual 0.75, expected 0.5, difference 0.25\n \
+              [3]: actual 2.0, expected 2.5, difference 0.5\n\
+             -[4]: NaN\n",
+        );
+        assert_eq!(approx_diff(&actual[..4], &actual[..4], Tolerance::Ulps(0)), None);
+    }
+}
+
+#[cfg(test)]
+mod messages {
+    use crate::assert_panics;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn nth() {
+        let vec = vec![1, 3, 5];
+        assert_panics!({ assert_nth!(&vec, 1, value == &4) }, message == "assertion failed: `value == &4` where `value = &vec[1]`\n  left: 3\n right: 4");
+        assert_panics!({ assert_nth!(&vec, 1, value != &3) }, message == "assertion failed: `value != &3` where `value = &vec[1]`\n  left: 3\n right: 3");
+        assert_panics!({ assert_nth!(&vec, 1, value < &3) }, message == "assertion failed: `value < &3` where `value = &vec[1]`\n  left: 3\n right: 3");
+        assert_panics!({ assert_nth!(&vec, 1, value <= &2) }, message == "assertion failed: `value <= &2` where `value = &vec[1]`\n  left: 3\n right: 2");
+        assert_panics!({ assert_nth!(&vec, 1, value > &3) }, message == "assertion failed: `value > &3` where `value = &vec[1]`\n  left: 3\n right: 3");
+        assert_panics!({ assert_nth!(&vec, 1, value >= &4) }, message == "assertion failed: `value >= &4` where `value = &vec[1]`\n  left: 3\n right: 4");
+        assert_panics!({ assert_nth!(&vec, 5, value == &1) }, message == "assertion failed: `value == &1` where `value = &vec[5]`\n `&vec[5]` does not exist");
+        assert_panics!({ assert_nth!(&vec, 1, value => |x| **x > 3) }, message == "assertion failed: `value` matches `|x| **x > 3` where `value = &vec[1]`\n value: 3");
+        assert_panics!({ assert_nth!(&vec, 5, value => |x| **x > 3) }, message == "assertion failed: `value` matches `|x| **x > 3` where `value = &vec[5]`\n `&vec[5]` does not exist");
+    }
+
+    #[test]
+    fn nth_back() {
+        let vec = vec![1, 3, 5];
+        assert_panics!({ assert_nth!(&vec, -1, value == &4) }, message == "assertion failed: `value == &4` where `value = &vec[-1]`\n  left: 5\n right: 4");
+        assert_panics!({ assert_nth!(&vec, -1, value != &5) }, message == "assertion failed: `value != &5` where `value = &vec[-1]`\n  left: 5\n right: 5");
+        assert_panics!({ assert_nth!(&vec, -1, value < &5) }, message == "assertion failed: `value < &5` where `value = &vec[-1]`\n  left: 5\n right: 5");
+        assert_panics!({ assert_nth!(&vec, -1, value <= &4) }, message == "assertion failed: `value <= &4` where `value = &vec[-1]`\n  left: 5\n right: 4");
+        assert_panics!({ assert_nth!(&vec, -1, value > &5) }, message == "assertion failed: `value > &5` where `value = &vec[-1]`\n  left: 5\n right: 5");
+        assert_panics!({ assert_nth!(&vec, -1, value >= &6) }, message == "assertion failed: `value >= &6` where `value = &vec[-1]`\n  left: 5\n right: 6");
+        assert_panics!({ assert_nth!(&vec, -4, value == &1) }, message == "assertion failed: `value == &1` where `value = &vec[-4]`\n `&vec[-4]` does not exist");
+        assert_panics!({ assert_nth!(&vec, -2, value => |x| **x > 3) }, message == "assertion failed: `value` matches `|x| **x > 3` where `value = &vec[-2]`\n value: 3");
+        assert_panics!({ assert_nth!(&vec, -4, value => |x| **x > 3) }, message == "assertion failed: `value` matches `|x| **x > 3` where `value = &vec[-4]`\n `&vec[-4]` does not exist");
+    }
+
+    #[test]
+    // The `diff` feature renders these multi-line values with `{:#?}`.
+    #[cfg(not(feature = "diff"))]
+    fn slice() {
+        let vec = vec![1, 3, 5, 7];
+        assert_panics!({ assert_slice!(&vec, 1..3, == &[3, 6]) }, message == "assertion failed: `value == &[3, 6]` where `value = &vec[1..3]`\n  left: [3, 5]\n right: [3, 6]");
+        assert_panics!({ assert_slice!(&vec, 1..3, != &[3, 5]) }, message == "assertion failed: `value != &[3, 5]` where `value = &vec[1..3]`\n  left: [3, 5]\n right: [3, 5]");
+        assert_panics!({ assert_slice!(&vec, 1..3, < &[3, 5]) }, message == "assertion failed: `value < &[3, 5]` where `value = &vec[1..3]`\n  left: [3, 5]\n right: [3, 5]");
+        assert_panics!({ assert_slice!(&vec, 1..3, <= &[3, 4]) }, message == "assertion failed: `value <= &[3, 4]` where `value = &vec[1..3]`\n  left: [3, 5]\n right: [3, 4]");
+        assert_panics!({ assert_slice!(&vec, 1..3, > &[3, 5]) }, message == "assertion failed: `value > &[3, 5]` where `value = &vec[1..3]`\n  left: [3, 5]\n right: [3, 5]");
+        assert_panics!({ assert_slice!(&vec, 1..3, >= &[3, 6]) }, message == "assertion failed: `value >= &[3, 6]` where `value = &vec[1..3]`\n  left: [3, 5]\n right: [3, 6]");
+        assert_panics!({ assert_slice!(&vec, 2..6, == &[5, 7]) }, message == "assertion failed: `value == &[5, 7]` where `value = &vec[2..6]`\n `&vec[2..6]` does not exist");
+    }
+
+    #[test]
+    fn contains() {
+        let vec = vec![1, 3, 5];
+        assert_panics!({ assert_contains!(&vec, &4) }, message == "assertion failed: `&vec` contains `&4`\n  left: [1, 3, 5]\n right: 4");
+        assert_panics!({ assert_contains!(&vec, where |x| *x % 2 == 0) }, message == "assertion failed: `&vec` contains an item matching `|x| *x % 2 == 0`\n value: [1, 3, 5]");
+    }
+
+    #[test]
+    fn all() {
+        let vec = vec![1, 2, 3];
+        assert_panics!({ assert_all!(&vec, |&x| x < 3) }, message == "assertion failed: all items of `&vec` match `|&x| x < 3`\n value: [1, 2, 3]");
+        assert_panics!({ assert_all!(&vec, |&x| x < 3, "all below {}", 3) }, message == "assertion failed: all items of `&vec` match `|&x| x < 3`: all below 3\n value: [1, 2, 3]");
+    }
+
+    #[test]
+    fn any() {
+        let vec = vec![1, 2, 3];
+        assert_panics!({ assert_any!(&vec, |&x| x > 3) }, message == "assertion failed: any item of `&vec` matches `|&x| x > 3`\n value: [1, 2, 3]");
+        assert_panics!({ assert_any!(&vec, |&x| x > 3, "any above {}", 3) }, message == "assertion failed: any item of `&vec` matches `|&x| x > 3`: any above 3\n value: [1, 2, 3]");
+    }
+
+    #[test]
+    fn seq_eq() {
+        let actual = vec![1, 2, 3, 4];
+        let expected = [1, 5, 3];
+        assert_panics!({ assert_seq_eq!(&actual, &expected) }, message == "assertion failed: `&actual == &expected`\n length: actual has 4 elements, expected has 3\n-[1]: 2\n+[1]: 5\n-[3]: 4");
+        assert_panics!({ assert_seq_eq!(&actual, &expected, "{} elements", 3) }, message == "assertion failed: `&actual == &expected`: 3 elements\n length: actual has 4 elements, expected has 3\n-[1]: 2\n+[1]: 5\n-[3]: 4");
+    }
+
+    #[test]
+    fn contains_key() {
+        let map: BTreeMap<_, _> = vec![("one", 1), ("two", 2)].into_iter().collect();
+        assert_panics!({ assert_contains_key!(&map, &"three") }, message == "assertion failed: `&map` contains key `&\"three\"`\n  left: {\"one\": 1, \"two\": 2}\n right: \"three\"");
+        assert_panics!({ assert_not_contains_key!(&map, &"one") }, message == "assertion failed: `&map` does not contain key `&\"one\"`\n  left: {\"one\": 1, \"two\": 2}\n right: \"one\"");
+    }
+
+    #[test]
+    fn key() {
+        let map: BTreeMap<_, _> = vec![("one", 1), ("two", 2)].into_iter().collect();
+        assert_panics!({ assert_key!(&map, &"two", value == &3) }, message == "assertion failed: `value == &3` where `value = &map[&\"two\"]`\n  left: 2\n right: 3\n   key: \"two\"");
+        assert_panics!({ assert_key!(&map, &"two", value != &2) }, message == "assertion failed: `value != &2` where `value = &map[&\"two\"]`\n  left: 2\n right: 2\n   key: \"two\"");
+        assert_panics!({ assert_key!(&map, &"two", value < &2) }, message == "assertion failed: `value < &2` where `value = &map[&\"two\"]`\n  left: 2\n right: 2\n   key: \"two\"");
+        assert_panics!({ assert_key!(&map, &"two", value <= &1) }, message == "assertion failed: `value <= &1` where `value = &map[&\"two\"]`\n  left: 2\n right: 1\n   key: \"two\"");
+        assert_panics!({ assert_key!(&map, &"two", value > &2) }, message == "assertion failed: `value > &2` where `value = &map[&\"two\"]`\n  left: 2\n right: 2\n   key: \"two\"");
+        assert_panics!({ assert_key!(&map, &"two", value >= &3) }, message == "assertion failed: `value >= &3` where `value = &map[&\"two\"]`\n  left: 2\n right: 3\n   key: \"two\"");
+        assert_panics!({ assert_key!(&map, &"six", value == &6) }, message == "assertion failed: `value == &6` where `value = &map[&\"six\"]`\n key: \"six\"\n `&map[&\"six\"]` does not exist");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn map_eq() {
+        let actual: BTreeMap<_, _> = vec![("one", 1), ("two", 2), ("four", 4)].into_iter().collect();
+        let expected: BTreeMap<_, _> = vec![("one", 1), ("two", 3), ("three", 3)].into_iter().collect();
+        assert_panics!({ assert_map_eq!(&actual, &expected) }, message == "assertion failed: `&actual == &expected`\n missing keys:\n+[\"three\"]: 3\n extra keys:\n-[\"four\"]: 4\n differing values:\n-[\"two\"]: 2\n+[\"two\"]: 3");
+        assert_panics!({ assert_map_eq!(&actual, &expected, "{} entries", 3) }, message == "assertion failed: `&actual == &expected`: 3 entries\n missing keys:\n+[\"three\"]: 3\n extra keys:\n-[\"four\"]: 4\n differing values:\n-[\"two\"]: 2\n+[\"two\"]: 3");
+    }
+
+    #[test]
+    fn count() {
+        let vec: Vec<Result<u8, u8>> = vec![Ok(1), Err(2), Ok(3), Err(4)];
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), == 1) }, message == "assertion failed: `count == 1` for items of `&vec` matching `|x| x.is_err()`\n  left: 2\n right: 1\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), == 1, "{}", "errors") }, message == "assertion failed: `count == 1` for items of `&vec` matching `|x| x.is_err()`: errors\n  left: 2\n right: 1\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), != 2) }, message == "assertion failed: `count != 2` for items of `&vec` matching `|x| x.is_err()`\n  left: 2\n right: 2\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), != 2, "{}", "errors") }, message == "assertion failed: `count != 2` for items of `&vec` matching `|x| x.is_err()`: errors\n  left: 2\n right: 2\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), < 2) }, message == "assertion failed: `count < 2` for items of `&vec` matching `|x| x.is_err()`\n  left: 2\n right: 2\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), < 2, "{}", "errors") }, message == "assertion failed: `count < 2` for items of `&vec` matching `|x| x.is_err()`: errors\n  left: 2\n right: 2\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), <= 1) }, message == "assertion failed: `count <= 1` for items of `&vec` matching `|x| x.is_err()`\n  left: 2\n right: 1\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), <= 1, "{}", "errors") }, message == "assertion failed: `count <= 1` for items of `&vec` matching `|x| x.is_err()`: errors\n  left: 2\n right: 1\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), > 2) }, message == "assertion failed: `count > 2` for items of `&vec` matching `|x| x.is_err()`\n  left: 2\n right: 2\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), > 2, "{}", "errors") }, message == "assertion failed: `count > 2` for items of `&vec` matching `|x| x.is_err()`: errors\n  left: 2\n right: 2\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), >= 3) }, message == "assertion failed: `count >= 3` for items of `&vec` matching `|x| x.is_err()`\n  left: 2\n right: 3\n [1]: Err(2)\n [3]: Err(4)");
+        assert_panics!({ assert_count!(&vec, |x| x.is_err(), >= 3, "{}", "errors") }, message == "assertion failed: `count >= 3` for items of `&vec` matching `|x| x.is_err()`: errors\n  left: 2\n right: 3\n [1]: Err(2)\n [3]: Err(4)");
+    }
+
+    #[test]
+    fn exactly_one() {
+        let vec = vec![1, 2, 3, 4];
+        assert_panics!({ assert_exactly_one!(&vec, |&x| x > 2) }, message == "assertion failed: `count == 1` for items of `&vec` matching `|&x| x > 2`\n  left: 2\n right: 1\n [2]: 3\n [3]: 4");
+        assert_panics!({ assert_exactly_one!(&vec, |&x| x > 2, "{}", "above two") }, message == "assertion failed: `count == 1` for items of `&vec` matching `|&x| x > 2`: above two\n  left: 2\n right: 1\n [2]: 3\n [3]: 4");
+        assert_panics!({ assert_exactly_one!(&vec, |&x| x > 4) }, message == "assertion failed: `count == 1` for items of `&vec` matching `|&x| x > 4`\n  left: 0\n right: 1");
+    }
+
+    #[test]
+    fn none_match() {
+        let vec = vec![1, 2, 3, 4];
+        assert_panics!({ assert_none_match!(&vec, |&x| x % 2 == 0) }, message == "assertion failed: `count == 0` for items of `&vec` matching `|&x| x % 2 == 0`\n  left: 2\n right: 0\n [1]: 2\n [3]: 4");
+        assert_panics!({ assert_none_match!(&vec, |&x| x % 2 == 0, "{}", "even") }, message == "assertion failed: `count == 0` for items of `&vec` matching `|&x| x % 2 == 0`: even\n  left: 2\n right: 0\n [1]: 2\n [3]: 4");
+    }
+
+    #[test]
+    fn all_approx_eq() {
+        let actual = vec![1.0, 2.0, 3.5, f64::NAN];
+        let expected = vec![1.0, 2.5, 3.0, 4.0];
+        assert_panics!({ assert_all_approx_eq!(&actual, &expected, abs = 0.1) }, message == "assertion failed: `&actual ≈ &expected`\n outside tolerance: 3 of 4 elements\n tolerance: 0.1 (absolute)\n max absolute error: 0.5 at [1]\n mean absolute error: 0.3333333333333333\n [1]: actual 2.0, expected 2.5, difference 0.5\n [2]: actual 3.5, expected 3.0, difference 0.5\n [3]: actual NaN, expected 4.0, NaN");
+        assert_panics!({ assert_all_approx_eq!(&actual, &expected, abs = 0.1, "{}", "signal") }, message == "assertion failed: `&actual ≈ &expected`: signal\n outside tolerance: 3 of 4 elements\n tolerance: 0.1 (absolute)\n max absolute error: 0.5 at [1]\n mean absolute error: 0.3333333333333333\n [1]: actual 2.0, expected 2.5, difference 0.5\n [2]: actual 3.5, expected 3.0, difference 0.5\n [3]: actual NaN, expected 4.0, NaN");
+        assert_panics!({ assert_all_approx_eq!(&actual, &expected, rel = 0.1) }, message == "assertion failed: `&actual ≈ &expected`\n outside tolerance: 3 of 4 elements\n tolerance: 0.1 (relative)\n max absolute error: 0.5 at [1]\n mean absolute error: 0.3333333333333333\n [1]: actual 2.0, expected 2.5, difference 0.2\n [2]: actual 3.5, expected 3.0, difference 0.14285714285714285\n [3]: actual NaN, expected 4.0, NaN");
+        assert_panics!({ assert_all_approx_eq!(&actual, &expected, rel = 0.1, "{}", "signal") }, message == "assertion failed: `&actual ≈ &expected`: signal\n outside tolerance: 3 of 4 elements\n tolerance: 0.1 (relative)\n max absolute error: 0.5 at [1]\n mean absolute error: 0.3333333333333333\n [1]: actual 2.0, expected 2.5, difference 0.2\n [2]: actual 3.5, expected 3.0, difference 0.14285714285714285\n [3]: actual NaN, expected 4.0, NaN");
+        assert_panics!({ assert_all_approx_eq!(&actual, &expected, ulps = 4) }, message == "assertion failed: `&actual ≈ &expected`\n outside tolerance: 3 of 4 elements\n tolerance: 4 (ulps)\n max absolute error: 0.5 at [1]\n mean absolute error: 0.3333333333333333\n [1]: actual 2.0, expected 2.5, difference 1125899906842624\n [2]: actual 3.5, expected 3.0, difference 1125899906842624\n [3]: actual NaN, expected 4.0, NaN");
+        assert_panics!({ assert_all_approx_eq!(&actual, &expected, ulps = 4, "{}", "signal") }, message == "assertion failed: `&actual ≈ &expected`: signal\n outside tolerance: 3 of 4 elements\n tolerance: 4 (ulps)\n max absolute error: 0.5 at [1]\n mean absolute error: 0.3333333333333333\n [1]: actual 2.0, expected 2.5, difference 1125899906842624\n [2]: actual 3.5, expected 3.0, difference 1125899906842624\n [3]: actual NaN, expected 4.0, NaN");
+        assert_panics!({ assert_all_approx_eq!(&actual[..2], &expected[..1], abs = 0.1) }, message == "assertion failed: `&actual[..2] ≈ &expected[..1]`\n length: actual has 2 elements, expected has 1\n outside tolerance: 1 of 2 elements\n tolerance: 0.1 (absolute)\n max absolute error: 0.0 at [0]\n mean absolute error: 0.0\n-[1]: 2.0");
+    }
+}
@@ -0,0 +1,170 @@
+//=============================================================================================
+// Macros
+//=============================================================================================
+
+/// Defines a `debug_*` macro for each assertion, which forwards its arguments to the assertion
+/// in builds with debug assertions enabled.
+///
+/// The first token is a `$`, passed in so that the generated macros can declare their own
+/// repetitions. Attributes on an entry, like a `cfg` for a feature, are copied onto its macro.
+macro_rules! debug_variants {
+    ($d:tt $($(#[$meta:meta])* $debug:ident => $assert:ident),+ $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[doc = concat!(
+                "Asserts like [", stringify!($assert), "](macro.", stringify!($assert), ".html), ",
+                "but only in builds with debug assertions enabled."
+            )]
+            ///
+            /// Like [debug_assert](https://doc.rust-lang.org/std/macro.debug_assert.html), the
+            /// arguments are always type checked, but they are only evaluated when
+            /// `debug_assertions` is enabled. The macro evaluates to `()` in every build, since a
+            /// compiled out assertion has no value to return.
+            #[macro_export]
+            macro_rules! $debug {
+                ($d($d arg:tt)*) => {
+                    if cfg!(debug_assertions) {
+                        $crate::$assert!($d($d arg)*);
+                    }
+                };
+            }
+        )+
+    };
+}
+
+debug_variants! { $
+    debug_assert_ok => assert_ok,
+    debug_assert_err => assert_err,
+    debug_assert_some => assert_some,
+    debug_assert_none => assert_none,
+
+    debug_assert_0th => assert_0th,
+    debug_assert_1st => assert_1st,
+    debug_assert_2nd => assert_2nd,
+    debug_assert_3rd => assert_3rd,
+    debug_assert_4th => assert_4th,
+    debug_assert_5th => assert_5th,
+    debug_assert_6th => assert_6th,
+    debug_assert_7th => assert_7th,
+    debug_assert_8th => assert_8th,
+    debug_assert_9th => assert_9th,
+    debug_assert_10th => assert_10th,
+    debug_assert_11th => assert_11th,
+    debug_assert_12th => assert_12th,
+    debug_assert_13th => assert_13th,
+    debug_assert_14th => assert_14th,
+    debug_assert_15th => assert_15th,
+
+    debug_assert_nth => assert_nth,
+    debug_assert_slice => assert_slice,
+    debug_assert_contains => assert_contains,
+    debug_assert_all => assert_all,
+    debug_assert_any => assert_any,
+    #[cfg(feature = "alloc")]
+    debug_assert_seq_eq => assert_seq_eq,
+    debug_assert_contains_key => assert_contains_key,
+    debug_assert_not_contains_key => assert_not_contains_key,
+    debug_assert_key => assert_key,
+    #[cfg(feature = "alloc")]
+    debug_assert_map_eq => assert_map_eq,
+    #[cfg(feature = "alloc")]
+    debug_assert_count => assert_count,
+    #[cfg(feature = "alloc")]
+    debug_assert_exactly_one => assert_exactly_one,
+    #[cfg(feature = "alloc")]
+    debug_assert_none_match => assert_none_match,
+    #[cfg(feature = "alloc")]
+    debug_assert_all_approx_eq => assert_all_approx_eq,
+
+    debug_assert_lt => assert_lt,
+    debug_assert_le => assert_le,
+    debug_assert_gt => assert_gt,
+    debug_assert_ge => assert_ge,
+    debug_assert_lt_by => assert_lt_by,
+    debug_assert_le_by => assert_le_by,
+    debug_assert_gt_by => assert_gt_by,
+    debug_assert_ge_by => assert_ge_by,
+    debug_assert_lt_by_key => assert_lt_by_key,
+    debug_assert_le_by_key => assert_le_by_key,
+    debug_assert_gt_by_key => assert_gt_by_key,
+    debug_assert_ge_by_key => assert_ge_by_key,
+    debug_assert_approx_eq => assert_approx_eq,
+    debug_assert_in_range => assert_in_range,
+    debug_assert_not_in_range => assert_not_in_range,
+    debug_assert_ordered => assert_ordered,
+
+    #[cfg(feature = "std")]
+    debug_assert_duration_near => assert_duration_near,
+    #[cfg(feature = "std")]
+    debug_assert_before => assert_before,
+    #[cfg(feature = "std")]
+    debug_assert_completes_within => assert_completes_within,
+
+    #[cfg(feature = "std")]
+    debug_assert_panics => assert_panics,
+
+    #[cfg(feature = "alloc")]
+    debug_check => check,
+}
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod variants {
+    use crate::assert_panics;
+
+    #[test]
+    fn correct() {
+        let result: Result<u32, ()> = Ok(5);
+        let tuple = (1, 2, 3, 4);
+        let vec = vec![1, 3, 5];
+        debug_assert_ok!(&result, value == &5);
+        debug_assert_3rd!(tuple, value == &4);
+        debug_assert_contains!(&vec, &3);
+        debug_assert_nth!(&vec, -1, value == &5);
+        debug_assert_lt!(1, 2);
+        debug_assert_ordered!(1 < 2 <= 2);
+        debug_assert_panics!({ vec[3] });
+        debug_check!(vec.len() == 3);
+    }
+    #[test]
+    fn trailing_comma() {
+        debug_assert_lt!(1, 2,);
+    }
+    #[test]
+    fn format_string() {
+        debug_assert_lt!(1, 2, "{} is less", 1);
+    }
+    #[test]
+    fn unit() {
+        let result: Result<u32, ()> = Ok(5);
+        let value: () = debug_assert_ok!(&result);
+        assert_eq!(value, ());
+    }
+    #[test]
+    #[cfg(debug_assertions)]
+    fn same_message() {
+        assert_panics!({ debug_assert_lt!(5, 5) }, message == "assertion failed: `5 < 5`\n  left: 5\n right: 5");
+        assert_panics!({ debug_assert_some!(&None::<u32>) }, message == "assertion failed: `&None::<u32> is Some(_)`\n value: None");
+    }
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn compiled_out() {
+        let mut calls = 0;
+        let mut next = || {
+            calls += 1;
+            calls
+        };
+        debug_assert_lt!(next(), 0);
+        assert_eq!(calls, 0);
+    }
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn incorrect() {
+        let vec = vec![1, 3, 5];
+        debug_assert_contains!(&vec, &4);
+    }
+}
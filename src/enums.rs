@@ -37,96 +37,107 @@
 /// ### Example Error Messages
 /// 
 /// ```text
-/// thread 'main' panicked at 'assertion failed: (&result is Ok(_))
-///  &result: Err(ParseIntError { kind: InvalidDigit })
-/// ', src/enums.rs:498:9
+/// thread 'main' panicked at 'assertion failed: `&result is Ok(_)`
+///  value: Err(ParseIntError { kind: InvalidDigit })'
 /// ```
 /// ```text
-/// thread 'main' panicked at 'assertion failed: (Ok(left) => { left <= right })
+/// thread 'main' panicked at 'assertion failed: `value <= &4` where `Ok(value) = &result`
 ///   left: 5
-///  right: 4
-/// ', src/enums.rs:465:9
+///  right: 4'
 /// ```
 #[macro_export]
 macro_rules! assert_ok {
     ($result:expr) => {{
         if let Err(_) = $result {
-            panic!(
-                "assertion failed: ({0} is Ok(_))\n {0}: {1:?}\n",
-                stringify!($result),
-                $result,
-            );
+            $crate::failure::fail("assert_ok", &$crate::report::Report::new(
+                format_args!("`{} is Ok(_)`", stringify!($result)),
+                &[
+                    $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", $result)),
+                ],
+            ).source("is Ok(_)", &[stringify!($result)]));
         }
     }};
     ($result:expr, value == $value:expr) => {{
-        assert_ok!($result);
+        $crate::assert_ok!($result);
         if let Ok(val) = $result {
             if val != $value {
-                panic!(
-                    "assertion failed: (Ok(left) => {{ left == right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_ok", &$crate::report::Report::new(
+                    format_args!("`value == {}` where `Ok(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("==", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value != $value:expr) => {{
-        assert_ok!($result);
+        $crate::assert_ok!($result);
         if let Ok(val) = $result {
             if val == $value {
-                panic!(
-                    "assertion failed: (Ok(left) => {{ left != right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_ok", &$crate::report::Report::new(
+                    format_args!("`value != {}` where `Ok(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("!=", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value < $value:expr) => {{
-        assert_ok!($result);
+        $crate::assert_ok!($result);
         if let Ok(val) = $result {
             if val >= $value {
-                panic!(
-                    "assertion failed: (Ok(left) => {{ left < right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_ok", &$crate::report::Report::new(
+                    format_args!("`value < {}` where `Ok(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("<", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value <= $value:expr) => {{
-        assert_ok!($result);
+        $crate::assert_ok!($result);
         if let Ok(val) = $result {
             if val > $value {
-                panic!(
-                    "assertion failed: (Ok(left) => {{ left <= right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_ok", &$crate::report::Report::new(
+                    format_args!("`value <= {}` where `Ok(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("<=", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value > $value:expr) => {{
-        assert_ok!($result);
+        $crate::assert_ok!($result);
         if let Ok(val) = $result {
             if val <= $value {
-                panic!(
-                    "assertion failed: (Ok(left) => {{ left > right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_ok", &$crate::report::Report::new(
+                    format_args!("`value > {}` where `Ok(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source(">", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value >= $value:expr) => {{
-        assert_ok!($result);
+        $crate::assert_ok!($result);
         if let Ok(val) = $result {
             if val < $value {
-                panic!(
-                    "assertion failed: (Ok(left) => {{ left >= right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_ok", &$crate::report::Report::new(
+                    format_args!("`value >= {}` where `Ok(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source(">=", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
@@ -167,96 +178,107 @@ macro_rules! assert_ok {
 /// ### Example Error Messages
 /// 
 /// ```text
-/// thread 'enums::err::is_err' panicked at 'assertion failed: (result is Err(_))
-///  result: Ok(5)
-/// ', src/enums.rs:574:9
+/// thread 'enums::err::is_err' panicked at 'assertion failed: `result is Err(_)`
+///  value: Ok(5)'
 /// ```
 /// ```text
-/// thread 'enums::err::eq_incorrect' panicked at 'assertion failed: (Err(left) => { left == right })
+/// thread 'enums::err::eq_incorrect' panicked at 'assertion failed: `value == &message` where `Err(value) = &result`
 ///   left: "This message matches."
-///  right: "This message doesn\'t match."
-/// ', src/enums.rs:491:9
+///  right: "This message doesn\'t match."'
 /// ```
 #[macro_export]
 macro_rules! assert_err {
     ($result:expr) => {{
         if let Ok(_) = $result {
-            panic!(
-                "assertion failed: ({0} is Err(_))\n {0}: {1:?}\n",
-                stringify!($result),
-                $result,
-            );
+            $crate::failure::fail("assert_err", &$crate::report::Report::new(
+                format_args!("`{} is Err(_)`", stringify!($result)),
+                &[
+                    $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", $result)),
+                ],
+            ).source("is Err(_)", &[stringify!($result)]));
         }
     }};
     ($result:expr, value == $value:expr) => {{
-        assert_err!($result);
+        $crate::assert_err!($result);
         if let Err(val) = $result {
             if val != $value {
-                panic!(
-                    "assertion failed: (Err(left) => {{ left == right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_err", &$crate::report::Report::new(
+                    format_args!("`value == {}` where `Err(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("==", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value != $value:expr) => {{
-        assert_err!($result);
+        $crate::assert_err!($result);
         if let Err(val) = $result {
             if val == $value {
-                panic!(
-                    "assertion failed: (Err(left) => {{ left != right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_err", &$crate::report::Report::new(
+                    format_args!("`value != {}` where `Err(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("!=", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value < $value:expr) => {{
-        assert_err!($result);
+        $crate::assert_err!($result);
         if let Err(val) = $result {
             if val >= $value {
-                panic!(
-                    "assertion failed: (Err(left) => {{ left < right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_err", &$crate::report::Report::new(
+                    format_args!("`value < {}` where `Err(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("<", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value <= $value:expr) => {{
-        assert_err!($result);
+        $crate::assert_err!($result);
         if let Err(val) = $result {
             if val > $value {
-                panic!(
-                    "assertion failed: (Err(left) => {{ left <= right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_err", &$crate::report::Report::new(
+                    format_args!("`value <= {}` where `Err(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("<=", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value > $value:expr) => {{
-        assert_err!($result);
+        $crate::assert_err!($result);
         if let Err(val) = $result {
             if val <= $value {
-                panic!(
-                    "assertion failed: (Err(left) => {{ left > right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_err", &$crate::report::Report::new(
+                    format_args!("`value > {}` where `Err(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source(">", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($result:expr, value >= $value:expr) => {{
-        assert_err!($result);
+        $crate::assert_err!($result);
         if let Err(val) = $result {
             if val < $value {
-                panic!(
-                    "assertion failed: (Err(left) => {{ left >= right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_err", &$crate::report::Report::new(
+                    format_args!("`value >= {}` where `Err(value) = {}`", stringify!($value), stringify!($result)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source(">=", &[stringify!($result), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
@@ -297,96 +319,107 @@ macro_rules! assert_err {
 /// ### Example Error Messages
 /// 
 /// ```text
-/// thread 'main' panicked at 'assertion failed: (&option is Some(_))
-///  &option: None
-/// ', src/enums.rs:699:9
+/// thread 'main' panicked at 'assertion failed: `&option is Some(_)`
+///  value: None'
 /// ```
 /// ```text
-/// thread 'main' panicked at 'assertion failed: (Some(left) => { left > right })
+/// thread 'main' panicked at 'assertion failed: `value > &5` where `Some(value) = &option`
 ///   left: 5
-///  right: 5
-/// ', src/enums.rs:679:9
+///  right: 5'
 /// ```
 #[macro_export]
 macro_rules! assert_some {
     ($option:expr) => {{
         if let None = $option {
-            panic!(
-                "assertion failed: ({0} is Some(_))\n {0}: {1:?}\n",
-                stringify!($option),
-                $option,
-            );
+            $crate::failure::fail("assert_some", &$crate::report::Report::new(
+                format_args!("`{} is Some(_)`", stringify!($option)),
+                &[
+                    $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", $option)),
+                ],
+            ).source("is Some(_)", &[stringify!($option)]));
         }
     }};
     ($option:expr, value == $value:expr) => {{
-        assert_some!($option);
+        $crate::assert_some!($option);
         if let Some(val) = $option {
             if val != $value {
-                panic!(
-                    "assertion failed: (Some(left) => {{ left == right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_some", &$crate::report::Report::new(
+                    format_args!("`value == {}` where `Some(value) = {}`", stringify!($value), stringify!($option)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("==", &[stringify!($option), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($option:expr, value != $value:expr) => {{
-        assert_some!($option);
+        $crate::assert_some!($option);
         if let Some(val) = $option {
             if val == $value {
-                panic!(
-                    "assertion failed: (Some(left) => {{ left != right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_some", &$crate::report::Report::new(
+                    format_args!("`value != {}` where `Some(value) = {}`", stringify!($value), stringify!($option)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("!=", &[stringify!($option), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($option:expr, value < $value:expr) => {{
-        assert_some!($option);
+        $crate::assert_some!($option);
         if let Some(val) = $option {
             if val >= $value {
-                panic!(
-                    "assertion failed: (Some(left) => {{ left < right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_some", &$crate::report::Report::new(
+                    format_args!("`value < {}` where `Some(value) = {}`", stringify!($value), stringify!($option)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("<", &[stringify!($option), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($option:expr, value <= $value:expr) => {{
-        assert_some!($option);
+        $crate::assert_some!($option);
         if let Some(val) = $option {
             if val > $value {
-                panic!(
-                    "assertion failed: (Some(left) => {{ left <= right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_some", &$crate::report::Report::new(
+                    format_args!("`value <= {}` where `Some(value) = {}`", stringify!($value), stringify!($option)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source("<=", &[stringify!($option), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($option:expr, value > $value:expr) => {{
-        assert_some!($option);
+        $crate::assert_some!($option);
         if let Some(val) = $option {
             if val <= $value {
-                panic!(
-                    "assertion failed: (Some(left) => {{ left > right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_some", &$crate::report::Report::new(
+                    format_args!("`value > {}` where `Some(value) = {}`", stringify!($value), stringify!($option)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source(">", &[stringify!($option), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
     ($option:expr, value >= $value:expr) => {{
-        assert_some!($option);
+        $crate::assert_some!($option);
         if let Some(val) = $option {
             if val < $value {
-                panic!(
-                    "assertion failed: (Some(left) => {{ left >= right }})\n  left: {:?}\n right: {:?}\n",
-                    val,
-                    $value,
-                )
+                $crate::failure::fail("assert_some", &$crate::report::Report::new(
+                    format_args!("`value >= {}` where `Some(value) = {}`", stringify!($value), stringify!($option)),
+                    &[
+                        $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&val))),
+                        $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$value))),
+                    ],
+                ).source(">=", &[stringify!($option), stringify!($value)]).details(format_args!("{}", $crate::report::Diff(&val, &$value))))
             }
         }
     }};
@@ -413,19 +446,19 @@ macro_rules! assert_some {
 /// ### Example Error Messages
 /// 
 /// ```text
-/// thread 'enums::none::is_some' panicked at 'assertion failed: (&option is None)
-///  &option: Some(5)
-/// ', src/enums.rs:743:9
+/// thread 'enums::none::is_some' panicked at 'assertion failed: `&option is None`
+///  value: Some(5)'
 /// ```
 #[macro_export]
 macro_rules! assert_none {
     ($option:expr) => {{
         if let Some(_) = $option {
-            panic!(
-                "assertion failed: ({0} is None)\n {0}: {1:?}\n",
-                stringify!($option),
-                $option,
-            );
+            $crate::failure::fail("assert_none", &$crate::report::Report::new(
+                format_args!("`{} is None`", stringify!($option)),
+                &[
+                    $crate::report::Field::actual(format_args!("value"), format_args!("{:?}", $option)),
+                ],
+            ).source("is None", &[stringify!($option)]));
         }
     }};
 }
@@ -743,4 +776,57 @@ mod none {
         let option = "5".parse::<u32>().ok();
         assert_none!(&option);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod messages {
+    use crate::assert_panics;
+
+    #[test]
+    fn ok() {
+        let ok: Result<u32, &str> = Ok(5);
+        let err: Result<u32, &str> = Err("e");
+        assert_panics!({ assert_ok!(&err) }, message == "assertion failed: `&err is Ok(_)`\n value: Err(\"e\")");
+        assert_panics!({ assert_ok!(&err, value == &5) }, message == "assertion failed: `&err is Ok(_)`\n value: Err(\"e\")");
+        assert_panics!({ assert_ok!(&ok, value == &6) }, message == "assertion failed: `value == &6` where `Ok(value) = &ok`\n  left: 5\n right: 6");
+        assert_panics!({ assert_ok!(&ok, value != &5) }, message == "assertion failed: `value != &5` where `Ok(value) = &ok`\n  left: 5\n right: 5");
+        assert_panics!({ assert_ok!(&ok, value < &5) }, message == "assertion failed: `value < &5` where `Ok(value) = &ok`\n  left: 5\n right: 5");
+        assert_panics!({ assert_ok!(&ok, value <= &4) }, message == "assertion failed: `value <= &4` where `Ok(value) = &ok`\n  left: 5\n right: 4");
+        assert_panics!({ assert_ok!(&ok, value > &5) }, message == "assertion failed: `value > &5` where `Ok(value) = &ok`\n  left: 5\n right: 5");
+        assert_panics!({ assert_ok!(&ok, value >= &6) }, message == "assertion failed: `value >= &6` where `Ok(value) = &ok`\n  left: 5\n right: 6");
+    }
+
+    #[test]
+    fn err() {
+        let ok: Result<u32, &str> = Ok(5);
+        let err: Result<u32, &str> = Err("e");
+        assert_panics!({ assert_err!(&ok) }, message == "assertion failed: `&ok is Err(_)`\n value: Ok(5)");
+        assert_panics!({ assert_err!(&ok, value == &"e") }, message == "assertion failed: `&ok is Err(_)`\n value: Ok(5)");
+        assert_panics!({ assert_err!(&err, value == &"f") }, message == "assertion failed: `value == &\"f\"` where `Err(value) = &err`\n  left: \"e\"\n right: \"f\"");
+        assert_panics!({ assert_err!(&err, value != &"e") }, message == "assertion failed: `value != &\"e\"` where `Err(value) = &err`\n  left: \"e\"\n right: \"e\"");
+        assert_panics!({ assert_err!(&err, value < &"e") }, message == "assertion failed: `value < &\"e\"` where `Err(value) = &err`\n  left: \"e\"\n right: \"e\"");
+        assert_panics!({ assert_err!(&err, value <= &"d") }, message == "assertion failed: `value <= &\"d\"` where `Err(value) = &err`\n  left: \"e\"\n right: \"d\"");
+        assert_panics!({ assert_err!(&err, value > &"e") }, message == "assertion failed: `value > &\"e\"` where `Err(value) = &err`\n  left: \"e\"\n right: \"e\"");
+        assert_panics!({ assert_err!(&err, value >= &"f") }, message == "assertion failed: `value >= &\"f\"` where `Err(value) = &err`\n  left: \"e\"\n right: \"f\"");
+    }
+
+    #[test]
+    fn some() {
+        let some = Some(5);
+        let none: Option<u32> = None;
+        assert_panics!({ assert_some!(&none) }, message == "assertion failed: `&none is Some(_)`\n value: None");
+        assert_panics!({ assert_some!(&none, value == &5) }, message == "assertion failed: `&none is Some(_)`\n value: None");
+        assert_panics!({ assert_some!(&some, value == &6) }, message == "assertion failed: `value == &6` where `Some(value) = &some`\n  left: 5\n right: 6");
+        assert_panics!({ assert_some!(&some, value != &5) }, message == "assertion failed: `value != &5` where `Some(value) = &some`\n  left: 5\n right: 5");
+        assert_panics!({ assert_some!(&some, value < &5) }, message == "assertion failed: `value < &5` where `Some(value) = &some`\n  left: 5\n right: 5");
+        assert_panics!({ assert_some!(&some, value <= &4) }, message == "assertion failed: `value <= &4` where `Some(value) = &some`\n  left: 5\n right: 4");
+        assert_panics!({ assert_some!(&some, value > &5) }, message == "assertion failed: `value > &5` where `Some(value) = &some`\n  left: 5\n right: 5");
+        assert_panics!({ assert_some!(&some, value >= &6) }, message == "assertion failed: `value >= &6` where `Some(value) = &some`\n  left: 5\n right: 6");
+    }
+
+    #[test]
+    fn none() {
+        let some = Some(5);
+        assert_panics!({ assert_none!(&some) }, message == "assertion failed: `&some is None`\n value: Some(5)");
+    }
+}
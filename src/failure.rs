@@ -0,0 +1,256 @@
+use crate::report::Report;
+use core::fmt;
+use core::panic::Location;
+#[cfg(feature = "color")]
+use std::backtrace::{Backtrace, BacktraceStatus};
+#[cfg(feature = "color")]
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::cell::Cell;
+#[cfg(feature = "color")]
+use std::sync::Once;
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock};
+#[cfg(feature = "color")]
+use std::{panic, thread};
+
+//=============================================================================================
+// Failure Handler
+//=============================================================================================
+
+/// A failed assertion, as passed to the [failure handler](fn.set_failure_handler.html).
+///
+/// Its `Display` implementation renders the message that the assertion panics with, without color.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct Failure<'a> {
+    /// The name of the macro that failed, e.g. `assert_ok`.
+    pub assertion: &'static str,
+    /// Where the macro was invoked.
+    pub location: &'static Location<'static>,
+    /// The failure message, without the location.
+    pub report: &'a Report<'a>,
+}
+
+impl fmt::Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.report, f)
+    }
+}
+
+/// A function that is called with every failed assertion.
+pub type FailureHandler = fn(&Failure<'_>);
+
+/// The handler that is installed by default, which does nothing, so failed assertions panic.
+pub fn default_failure_handler(_: &Failure<'_>) {}
+
+#[cfg(feature = "std")]
+static HANDLER: RwLock<FailureHandler> = RwLock::new(default_failure_handler);
+
+#[cfg(feature = "std")]
+thread_local! {
+    static THREAD_HANDLER: Cell<Option<FailureHandler>> = const { Cell::new(None) };
+}
+
+/// Sets the function that every totems macro calls when its assertion fails, and returns the
+/// previous one.
+///
+/// The handler is shared by every thread in the process, unless a thread overrides it with
+/// [set_thread_failure_handler](fn.set_thread_failure_handler.html). It runs before the assertion
+/// panics, so it can log or count failures, convert them into events for a test harness, or
+/// diverge in its own way, e.g. by aborting the process. If the handler returns, the assertion
+/// panics with its usual message: code after a failed assertion never runs, since many macros
+/// return a value.
+///
+/// ### Example
+///
+/// ```
+/// use totems::{assert_lt, set_failure_handler, Failure};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static FAILURES: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn count(failure: &Failure) {
+///     FAILURES.fetch_add(1, Ordering::Relaxed);
+///     eprintln!("{} failed at {}", failure.assertion, failure.location);
+/// }
+///
+/// let previous = set_failure_handler(count);
+/// let result = std::panic::catch_unwind(|| assert_lt!(2, 1));
+/// set_failure_handler(previous);
+/// assert!(result.is_err());
+/// assert_eq!(FAILURES.load(Ordering::Relaxed), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn set_failure_handler(handler: FailureHandler) -> FailureHandler {
+    let mut installed = HANDLER.write().unwrap_or_else(PoisonError::into_inner);
+    core::mem::replace(&mut *installed, handler)
+}
+
+/// Overrides the [failure handler](fn.set_failure_handler.html) on the current thread, or removes
+/// the override with `None`, and returns the previous override.
+///
+/// Tests run on threads of their own, so a test can install a handler this way without seeing
+/// the failures of the tests running next to it.
+///
+/// ### Example
+///
+/// ```
+/// use totems::{assert_lt, set_thread_failure_handler, Failure};
+/// use std::cell::Cell;
+///
+/// thread_local! {
+///     static FAILURES: Cell<usize> = Cell::new(0);
+/// }
+///
+/// fn count(_: &Failure) {
+///     FAILURES.with(|failures| failures.set(failures.get() + 1));
+/// }
+///
+/// let previous = set_thread_failure_handler(Some(count));
+/// let result = std::panic::catch_unwind(|| assert_lt!(2, 1));
+/// set_thread_failure_handler(previous);
+/// assert!(result.is_err());
+/// assert_eq!(FAILURES.with(Cell::get), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn set_thread_failure_handler(handler: Option<FailureHandler>) -> Option<FailureHandler> {
+    THREAD_HANDLER.with(|installed| installed.replace(handler))
+}
+
+/// Returns the function that totems macros call when their assertion fails on the current
+/// thread: its override, if it has one, and the handler shared by every thread otherwise.
+#[cfg(feature = "std")]
+pub fn failure_handler() -> FailureHandler {
+    match THREAD_HANDLER.with(Cell::get) {
+        Some(handler) => handler,
+        None => *HANDLER.read().unwrap_or_else(PoisonError::into_inner),
+    }
+}
+
+/// Reports a failed assertion to the [failure handler](fn.set_failure_handler.html), then panics
+/// with the rendered `report` at the location of the macro. Without the `std` feature, there is
+/// no handler to report to.
+///
+/// With the `json` feature, it first writes the [record](../json/struct.Record.html) of the failure
+/// to standard error, and with the `junit` feature, adds it to the
+/// [JUnit report](../junit/constant.VARIABLE.html) of the test binary.
+///
+/// The panic message is never colored, so that code catching the panic sees plain text. With the
+/// `color` feature, the panic hook writes a colored rendering to standard error instead.
+#[doc(hidden)]
+#[track_caller]
+pub fn fail(assertion: &'static str, report: &Report<'_>) -> ! {
+    let failure = Failure { assertion, location: Location::caller(), report };
+    #[cfg(feature = "json")]
+    crate::json::emit(&failure);
+    #[cfg(feature = "junit")]
+    crate::junit::collect(&failure);
+    #[cfg(feature = "std")]
+    failure_handler()(&failure);
+    #[cfg(not(feature = "std"))]
+    let _ = failure;
+    #[cfg(feature = "color")]
+    colored(report);
+    panic!("{}", report)
+}
+
+//=============================================================================================
+// Colored Output
+//=============================================================================================
+
+#[cfg(feature = "color")]
+thread_local! {
+    /// The colored rendering of the report that the current thread is about to panic with.
+    static COLORED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "color")]
+static INSTALL: Once = Once::new();
+
+/// Leaves a colored rendering of `report` for the panic hook to write, if messages are colored.
+///
+/// The first call wraps the current panic hook in one that writes the rendering in place of the
+/// plain message. Panics that [assert_panics](../macro.assert_panics.html) expects stay quiet.
+#[cfg(feature = "color")]
+fn colored(report: &Report<'_>) {
+    let colored = match crate::report::color() && !crate::panics::catching() {
+        true => Some(report.styled(true).to_string()),
+        false => None,
+    };
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match COLORED.with(RefCell::take) {
+            Some(message) => {
+                let current = thread::current();
+                let name = current.name().unwrap_or("<unnamed>");
+                match info.location() {
+                    Some(location) => eprintln!("\nthread '{}' panicked at {}:\n{}", name, location, message),
+                    None => eprintln!("\nthread '{}' panicked:\n{}", name, message),
+                }
+                let backtrace = Backtrace::capture();
+                match backtrace.status() {
+                    BacktraceStatus::Captured => eprintln!("stack backtrace:\n{}", backtrace),
+                    _ => eprintln!("note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"),
+                }
+            }
+            None => previous(info),
+        }));
+    });
+    COLORED.with(|pending| pending.replace(colored));
+}
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod handler {
+    use super::{failure_handler, set_failure_handler, set_thread_failure_handler, Failure, FailureHandler};
+    use crate::{assert_lt, assert_ok, assert_panics};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static SEEN: RefCell<Vec<(&'static str, u32, String, String)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(failure: &Failure) {
+        let source = format!("{} {:?}", failure.report.operator, failure.report.expressions);
+        SEEN.with(|seen| seen.borrow_mut().push((failure.assertion, failure.location.line(), failure.to_string(), source)));
+    }
+
+    // Failures on other test threads also reach the handler, so it records them per thread, and
+    // only this test swaps it.
+    #[test]
+    fn receives_failures() {
+        let previous: FailureHandler = set_failure_handler(record);
+        let line = line!() + 1;
+        let message = assert_panics!({ assert_lt!(2, 1) }, message contains "`2 < 1`");
+        assert_panics!({ assert_ok!(Err::<u32, u32>(5)) });
+        set_failure_handler(previous);
+
+        let seen = SEEN.with(|seen| seen.take());
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0], ("assert_lt", line, message, String::from("< [\"2\", \"1\"]")));
+        assert_eq!(seen[1].0, "assert_ok");
+        assert_eq!(seen[1].3, "is Ok(_) [\"Err::<u32, u32>(5)\"]");
+        assert_eq!(failure_handler() as usize, previous as usize);
+    }
+
+    fn ignore(_: &Failure) {}
+
+    // The override keeps out the failures of other threads, and of a handler set by the test above.
+    #[test]
+    fn thread_override() {
+        assert!(set_thread_failure_handler(Some(ignore)).is_none());
+        assert_eq!(set_thread_failure_handler(Some(record)).map(|handler| handler as usize), Some(ignore as FailureHandler as usize));
+        assert_eq!(failure_handler() as usize, record as FailureHandler as usize);
+        assert_panics!({ assert_lt!(3, 1) });
+        let previous = set_thread_failure_handler(None);
+
+        let seen = SEEN.with(|seen| seen.take());
+        assert_eq!(previous.map(|handler| handler as usize), Some(record as FailureHandler as usize));
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].3, "< [\"3\", \"1\"]");
+    }
+}
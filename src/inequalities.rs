@@ -1,3 +1,9 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Bound, RangeBounds};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
 //=============================================================================================
 // Macros
 //=============================================================================================
@@ -26,9 +32,9 @@
 /// ### Example Error Messages
 /// 
 /// ```text
-/// thread 'inequalities::lt::incorrect' panicked at 'assertion failed: `(left < right)`
-///   left: `5`,
-///  right: `5`', src/inequalities.rs:245:9
+/// thread 'inequalities::lt::incorrect' panicked at 'assertion failed: `x < y`
+///   left: 5
+///  right: 5'
 /// ```
 #[macro_export]
 macro_rules! assert_lt {
@@ -39,15 +45,19 @@ macro_rules! assert_lt {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left < right)`
-  left: `{:?}`,
- right: `{:?}`"#, &*left_val, &*right_val)
+                    $crate::failure::fail("assert_lt", &$crate::report::Report::new(
+                        format_args!("`{} < {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                        ],
+                    ).source("<", &[stringify!($left), stringify!($right)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
                 }
             }
         }
     });
     ($left:expr, $right:expr,) => ({
-        assert_lt!($left, $right)
+        $crate::assert_lt!($left, $right)
     });
     ($left:expr, $right:expr, $($arg:tt)+) => ({
         match (&($left), &($right)) {
@@ -56,10 +66,13 @@ macro_rules! assert_lt {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left < right)`
-  left: `{:?}`,
- right: `{:?}`: {}"#, &*left_val, &*right_val,
-                           format_args!($($arg)+))
+                    $crate::failure::fail("assert_lt", &$crate::report::Report::new(
+                        format_args!("`{} < {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                        ],
+                    ).source("<", &[stringify!($left), stringify!($right)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
                 }
             }
         }
@@ -90,9 +103,9 @@ macro_rules! assert_lt {
 /// ### Example Error Messages
 /// 
 /// ```text
-/// thread 'inequalities::le::incorrect' panicked at 'assertion failed: `(left <= right)`
-///   left: `6`,
-///  right: `5`', src/inequalities.rs:270:9
+/// thread 'inequalities::le::incorrect' panicked at 'assertion failed: `x <= y`
+///   left: 6
+///  right: 5'
 /// ```
 #[macro_export]
 macro_rules! assert_le {
@@ -103,15 +116,19 @@ macro_rules! assert_le {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left <= right)`
-  left: `{:?}`,
- right: `{:?}`"#, &*left_val, &*right_val)
+                    $crate::failure::fail("assert_le", &$crate::report::Report::new(
+                        format_args!("`{} <= {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                        ],
+                    ).source("<=", &[stringify!($left), stringify!($right)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
                 }
             }
         }
     });
     ($left:expr, $right:expr,) => ({
-        assert_le!($left, $right)
+        $crate::assert_le!($left, $right)
     });
     ($left:expr, $right:expr, $($arg:tt)+) => ({
         match (&($left), &($right)) {
@@ -120,10 +137,13 @@ macro_rules! assert_le {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left <= right)`
-  left: `{:?}`,
- right: `{:?}`: {}"#, &*left_val, &*right_val,
-                           format_args!($($arg)+))
+                    $crate::failure::fail("assert_le", &$crate::report::Report::new(
+                        format_args!("`{} <= {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                        ],
+                    ).source("<=", &[stringify!($left), stringify!($right)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
                 }
             }
         }
@@ -154,9 +174,9 @@ macro_rules! assert_le {
 /// ### Example Error Messages
 /// 
 /// ```text
-/// thread 'inequalities::gt::incorrect' panicked at 'assertion failed: `(left > right)`
-///   left: `5`,
-///  right: `5`', src/inequalities.rs:295:9
+/// thread 'inequalities::gt::incorrect' panicked at 'assertion failed: `x > y`
+///   left: 5
+///  right: 5'
 /// ```
 #[macro_export]
 macro_rules! assert_gt {
@@ -167,15 +187,19 @@ macro_rules! assert_gt {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left > right)`
-  left: `{:?}`,
- right: `{:?}`"#, &*left_val, &*right_val)
+                    $crate::failure::fail("assert_gt", &$crate::report::Report::new(
+                        format_args!("`{} > {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                        ],
+                    ).source(">", &[stringify!($left), stringify!($right)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
                 }
             }
         }
     });
     ($left:expr, $right:expr,) => ({
-        assert_gt!($left, $right)
+        $crate::assert_gt!($left, $right)
     });
     ($left:expr, $right:expr, $($arg:tt)+) => ({
         match (&($left), &($right)) {
@@ -184,10 +208,13 @@ macro_rules! assert_gt {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left > right)`
-  left: `{:?}`,
- right: `{:?}`: {}"#, &*left_val, &*right_val,
-                           format_args!($($arg)+))
+                    $crate::failure::fail("assert_gt", &$crate::report::Report::new(
+                        format_args!("`{} > {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                        ],
+                    ).source(">", &[stringify!($left), stringify!($right)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
                 }
             }
         }
@@ -218,9 +245,9 @@ macro_rules! assert_gt {
 /// ### Example Error Messages
 /// 
 /// ```text
-/// thread 'inequalities::ge::incorrect' panicked at 'assertion failed: `(left >= right)`
-///   left: `5`,
-///  right: `6`', src/inequalities.rs:320:9
+/// thread 'inequalities::ge::incorrect' panicked at 'assertion failed: `x >= y`
+///   left: 5
+///  right: 6'
 /// ```
 #[macro_export]
 macro_rules! assert_ge {
@@ -231,15 +258,19 @@ macro_rules! assert_ge {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left >= right)`
-  left: `{:?}`,
- right: `{:?}`"#, &*left_val, &*right_val)
+                    $crate::failure::fail("assert_ge", &$crate::report::Report::new(
+                        format_args!("`{} >= {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                        ],
+                    ).source(">=", &[stringify!($left), stringify!($right)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
                 }
             }
         }
     });
     ($left:expr, $right:expr,) => ({
-        assert_ge!($left, $right)
+        $crate::assert_ge!($left, $right)
     });
     ($left:expr, $right:expr, $($arg:tt)+) => ({
         match (&($left), &($right)) {
@@ -248,115 +279,1928 @@ macro_rules! assert_ge {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left >= right)`
-  left: `{:?}`,
- right: `{:?}`: {}"#, &*left_val, &*right_val,
-                           format_args!($($arg)+))
+                    $crate::failure::fail("assert_ge", &$crate::report::Report::new(
+                        format_args!("`{} >= {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                        ],
+                    ).source(">=", &[stringify!($left), stringify!($right)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
                 }
             }
         }
     });
 }
 
-//=============================================================================================
-// Unit Tests
-//=============================================================================================
+/// Asserts `(left  < right)`, ordered by a comparator.
+/// 
+/// ### Parameters
+/// 
+/// - `left` The left operand of the comparison.
+/// - `right` The right operand of the comparison.
+/// - `compare` A function from `(&left, &right)` to their [Ordering](https://doc.rust-lang.org/std/cmp/enum.Ordering.html).
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_lt_by;
+/// let x = (1, "b");
+/// let y = (2, "a");
+/// assert_lt_by!(x, y, |a, b| a.1.cmp(b.1).reverse())
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::lt_by::incorrect' panicked at 'assertion failed: `x < y` by `Ord::cmp`
+///      left: 5
+///     right: 5
+///  ordering: Equal'
+/// ```
+#[macro_export]
+macro_rules! assert_lt_by {
+    ($left:expr, $right:expr, $compare:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let ordering = $crate::inequalities::compare_by(left_val, right_val, $compare);
+                if ordering != ::core::cmp::Ordering::Less {
+                    $crate::failure::fail("assert_lt_by", &$crate::report::Report::new(
+                        format_args!("`{} < {}` by `{}`", stringify!($left), stringify!($right), stringify!($compare)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::other(format_args!("ordering"), format_args!("{:?}", ordering)),
+                        ],
+                    ).source("<", &[stringify!($left), stringify!($right), stringify!($compare)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $compare:expr,) => ({
+        $crate::assert_lt_by!($left, $right, $compare)
+    });
+    ($left:expr, $right:expr, $compare:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let ordering = $crate::inequalities::compare_by(left_val, right_val, $compare);
+                if ordering != ::core::cmp::Ordering::Less {
+                    $crate::failure::fail("assert_lt_by", &$crate::report::Report::new(
+                        format_args!("`{} < {}` by `{}`", stringify!($left), stringify!($right), stringify!($compare)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::other(format_args!("ordering"), format_args!("{:?}", ordering)),
+                        ],
+                    ).source("<", &[stringify!($left), stringify!($right), stringify!($compare)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
 
-#[cfg(test)]
-mod lt {
-    #[test]
-    fn correct() {
-        assert_lt!(5, 7);
-        assert_lt!(5, 6);
-    }
-    #[test]
-    fn trailing_comma() {
-        assert_lt!(5, 6,);
-    }
-    #[test]
-    fn format_string() {
-        let x = 5;
-        let y = 6;
-        assert_lt!(x, y, "{} is less than {}", x, y);
-    }
-    #[test]
-    #[should_panic]
-    fn incorrect() {
-        assert_lt!(5, 5);
-    }
+/// Asserts `(key(left)  < key(right))`.
+/// 
+/// ### Parameters
+/// 
+/// - `left` The left operand of the comparison.
+/// - `right` The right operand of the comparison.
+/// - `key` A function from `&left` and `&right` to the keys that are compared.
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - The keys must be at least [PartialOrd](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_lt_by_key;
+/// let x = ("b", 1);
+/// let y = ("a", 2);
+/// assert_lt_by_key!(x, y, |pair| pair.1)
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::lt_by_key::incorrect' panicked at 'assertion failed: `x < y` by key `|pair| pair.1`
+///       left: ("a", 5)
+///      right: ("b", 5)
+///   left key: 5
+///  right key: 5'
+/// ```
+#[macro_export]
+macro_rules! assert_lt_by_key {
+    ($left:expr, $right:expr, $key:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::inequalities::compare_by_key(left_val, right_val, $key);
+                if !(left_key < right_key) {
+                    $crate::failure::fail("assert_lt_by_key", &$crate::report::Report::new(
+                        format_args!("`{} < {}` by key `{}`", stringify!($left), stringify!($right), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::actual(format_args!("left key"), format_args!("{:?}", left_key)),
+                            $crate::report::Field::expected(format_args!("right key"), format_args!("{:?}", right_key)),
+                        ],
+                    ).source("<", &[stringify!($left), stringify!($right), stringify!($key)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $key:expr,) => ({
+        $crate::assert_lt_by_key!($left, $right, $key)
+    });
+    ($left:expr, $right:expr, $key:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::inequalities::compare_by_key(left_val, right_val, $key);
+                if !(left_key < right_key) {
+                    $crate::failure::fail("assert_lt_by_key", &$crate::report::Report::new(
+                        format_args!("`{} < {}` by key `{}`", stringify!($left), stringify!($right), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::actual(format_args!("left key"), format_args!("{:?}", left_key)),
+                            $crate::report::Field::expected(format_args!("right key"), format_args!("{:?}", right_key)),
+                        ],
+                    ).source("<", &[stringify!($left), stringify!($right), stringify!($key)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
 }
 
-#[cfg(test)]
-mod le {
-    #[test]
-    fn correct() {
-        assert_le!(5, 6);
-        assert_le!(5, 5);
-    }
-    #[test]
-    fn trailing_comma() {
-        assert_le!(5, 6,);
-        assert_le!(5, 5,);
-    }
-    #[test]
-    fn format_string() {
-        let x = 5;
-        let y = 6;
-        assert_le!(x, y, "{} is less or equal to {}", x, y);
-    }
-    #[test]
-    #[should_panic]
-    fn incorrect() {
-        assert_le!(6, 5);
-    }
+/// Asserts `(left <= right)`, ordered by a comparator.
+/// 
+/// ### Parameters
+/// 
+/// - `left` The left operand of the comparison.
+/// - `right` The right operand of the comparison.
+/// - `compare` A function from `(&left, &right)` to their [Ordering](https://doc.rust-lang.org/std/cmp/enum.Ordering.html).
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_le_by;
+/// let x = (1, "b");
+/// let y = (2, "a");
+/// assert_le_by!(x, y, |a, b| a.1.cmp(b.1).reverse())
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::le_by::incorrect' panicked at 'assertion failed: `x <= y` by `Ord::cmp`
+///      left: 6
+///     right: 5
+///  ordering: Greater'
+/// ```
+#[macro_export]
+macro_rules! assert_le_by {
+    ($left:expr, $right:expr, $compare:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let ordering = $crate::inequalities::compare_by(left_val, right_val, $compare);
+                if ordering == ::core::cmp::Ordering::Greater {
+                    $crate::failure::fail("assert_le_by", &$crate::report::Report::new(
+                        format_args!("`{} <= {}` by `{}`", stringify!($left), stringify!($right), stringify!($compare)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::other(format_args!("ordering"), format_args!("{:?}", ordering)),
+                        ],
+                    ).source("<=", &[stringify!($left), stringify!($right), stringify!($compare)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $compare:expr,) => ({
+        $crate::assert_le_by!($left, $right, $compare)
+    });
+    ($left:expr, $right:expr, $compare:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let ordering = $crate::inequalities::compare_by(left_val, right_val, $compare);
+                if ordering == ::core::cmp::Ordering::Greater {
+                    $crate::failure::fail("assert_le_by", &$crate::report::Report::new(
+                        format_args!("`{} <= {}` by `{}`", stringify!($left), stringify!($right), stringify!($compare)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::other(format_args!("ordering"), format_args!("{:?}", ordering)),
+                        ],
+                    ).source("<=", &[stringify!($left), stringify!($right), stringify!($compare)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
 }
 
-#[cfg(test)]
-mod gt {
-    #[test]
-    fn correct() {
-        assert_gt!(7, 5);
-        assert_gt!(6, 5);
-    }
-    #[test]
-    fn trailing_comma() {
-        assert_gt!(7, 5,);
-        assert_gt!(6, 5,);
-    }
-    #[test]
-    fn format_string() {
-        let x = 7;
-        let y = 5;
-        assert_gt!(x, y, "{} is greater than {}", x, y);
-    }
-    #[test]
-    #[should_panic]
-    fn incorrect() {
-        assert_gt!(5, 5);
-    }
+/// Asserts `(key(left) <= key(right))`.
+/// 
+/// ### Parameters
+/// 
+/// - `left` The left operand of the comparison.
+/// - `right` The right operand of the comparison.
+/// - `key` A function from `&left` and `&right` to the keys that are compared.
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - The keys must be at least [PartialOrd](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_le_by_key;
+/// let x = ("b", 1);
+/// let y = ("a", 2);
+/// assert_le_by_key!(x, y, |pair| pair.1)
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::le_by_key::incorrect' panicked at 'assertion failed: `x <= y` by key `|pair| pair.1`
+///       left: ("a", 6)
+///      right: ("b", 5)
+///   left key: 6
+///  right key: 5'
+/// ```
+#[macro_export]
+macro_rules! assert_le_by_key {
+    ($left:expr, $right:expr, $key:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::inequalities::compare_by_key(left_val, right_val, $key);
+                if !(left_key <= right_key) {
+                    $crate::failure::fail("assert_le_by_key", &$crate::report::Report::new(
+                        format_args!("`{} <= {}` by key `{}`", stringify!($left), stringify!($right), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::actual(format_args!("left key"), format_args!("{:?}", left_key)),
+                            $crate::report::Field::expected(format_args!("right key"), format_args!("{:?}", right_key)),
+                        ],
+                    ).source("<=", &[stringify!($left), stringify!($right), stringify!($key)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $key:expr,) => ({
+        $crate::assert_le_by_key!($left, $right, $key)
+    });
+    ($left:expr, $right:expr, $key:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::inequalities::compare_by_key(left_val, right_val, $key);
+                if !(left_key <= right_key) {
+                    $crate::failure::fail("assert_le_by_key", &$crate::report::Report::new(
+                        format_args!("`{} <= {}` by key `{}`", stringify!($left), stringify!($right), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::actual(format_args!("left key"), format_args!("{:?}", left_key)),
+                            $crate::report::Field::expected(format_args!("right key"), format_args!("{:?}", right_key)),
+                        ],
+                    ).source("<=", &[stringify!($left), stringify!($right), stringify!($key)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
 }
 
-#[cfg(test)]
-mod ge {
-    #[test]
-    fn correct() {
-        assert_ge!(6, 6);
-        assert_ge!(5, 5);
-    }
-    #[test]
-    fn trailing_comma() {
-        assert_ge!(6, 6,);
-        assert_ge!(5, 5,);
-    }
-    #[test]
-    fn format_string() {
-        let x = 6;
-        let y = 5;
-        assert_ge!(x, y, "{} is greater or equal to {}", x, y);
-    }
-    #[test]
-    #[should_panic]
-    fn incorrect() {
-        assert_ge!(5, 6);
+/// Asserts `(left  > right)`, ordered by a comparator.
+/// 
+/// ### Parameters
+/// 
+/// - `left` The left operand of the comparison.
+/// - `right` The right operand of the comparison.
+/// - `compare` A function from `(&left, &right)` to their [Ordering](https://doc.rust-lang.org/std/cmp/enum.Ordering.html).
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_gt_by;
+/// let x = (1, "b");
+/// let y = (2, "a");
+/// assert_gt_by!(y, x, |a, b| a.1.cmp(b.1).reverse())
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::gt_by::incorrect' panicked at 'assertion failed: `x > y` by `Ord::cmp`
+///      left: 5
+///     right: 5
+///  ordering: Equal'
+/// ```
+#[macro_export]
+macro_rules! assert_gt_by {
+    ($left:expr, $right:expr, $compare:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let ordering = $crate::inequalities::compare_by(left_val, right_val, $compare);
+                if ordering != ::core::cmp::Ordering::Greater {
+                    $crate::failure::fail("assert_gt_by", &$crate::report::Report::new(
+                        format_args!("`{} > {}` by `{}`", stringify!($left), stringify!($right), stringify!($compare)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::other(format_args!("ordering"), format_args!("{:?}", ordering)),
+                        ],
+                    ).source(">", &[stringify!($left), stringify!($right), stringify!($compare)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $compare:expr,) => ({
+        $crate::assert_gt_by!($left, $right, $compare)
+    });
+    ($left:expr, $right:expr, $compare:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let ordering = $crate::inequalities::compare_by(left_val, right_val, $compare);
+                if ordering != ::core::cmp::Ordering::Greater {
+                    $crate::failure::fail("assert_gt_by", &$crate::report::Report::new(
+                        format_args!("`{} > {}` by `{}`", stringify!($left), stringify!($right), stringify!($compare)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::other(format_args!("ordering"), format_args!("{:?}", ordering)),
+                        ],
+                    ).source(">", &[stringify!($left), stringify!($right), stringify!($compare)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
+
+/// Asserts `(key(left)  > key(right))`.
+/// 
+/// ### Parameters
+/// 
+/// - `left` The left operand of the comparison.
+/// - `right` The right operand of the comparison.
+/// - `key` A function from `&left` and `&right` to the keys that are compared.
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - The keys must be at least [PartialOrd](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_gt_by_key;
+/// let x = ("b", 1);
+/// let y = ("a", 2);
+/// assert_gt_by_key!(y, x, |pair| pair.1)
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::gt_by_key::incorrect' panicked at 'assertion failed: `x > y` by key `|pair| pair.1`
+///       left: ("a", 5)
+///      right: ("b", 5)
+///   left key: 5
+///  right key: 5'
+/// ```
+#[macro_export]
+macro_rules! assert_gt_by_key {
+    ($left:expr, $right:expr, $key:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::inequalities::compare_by_key(left_val, right_val, $key);
+                if !(left_key > right_key) {
+                    $crate::failure::fail("assert_gt_by_key", &$crate::report::Report::new(
+                        format_args!("`{} > {}` by key `{}`", stringify!($left), stringify!($right), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::actual(format_args!("left key"), format_args!("{:?}", left_key)),
+                            $crate::report::Field::expected(format_args!("right key"), format_args!("{:?}", right_key)),
+                        ],
+                    ).source(">", &[stringify!($left), stringify!($right), stringify!($key)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $key:expr,) => ({
+        $crate::assert_gt_by_key!($left, $right, $key)
+    });
+    ($left:expr, $right:expr, $key:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::inequalities::compare_by_key(left_val, right_val, $key);
+                if !(left_key > right_key) {
+                    $crate::failure::fail("assert_gt_by_key", &$crate::report::Report::new(
+                        format_args!("`{} > {}` by key `{}`", stringify!($left), stringify!($right), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::actual(format_args!("left key"), format_args!("{:?}", left_key)),
+                            $crate::report::Field::expected(format_args!("right key"), format_args!("{:?}", right_key)),
+                        ],
+                    ).source(">", &[stringify!($left), stringify!($right), stringify!($key)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
+
+/// Asserts `(left >= right)`, ordered by a comparator.
+/// 
+/// ### Parameters
+/// 
+/// - `left` The left operand of the comparison.
+/// - `right` The right operand of the comparison.
+/// - `compare` A function from `(&left, &right)` to their [Ordering](https://doc.rust-lang.org/std/cmp/enum.Ordering.html).
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_ge_by;
+/// let x = (1, "b");
+/// let y = (2, "a");
+/// assert_ge_by!(y, x, |a, b| a.1.cmp(b.1).reverse())
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::ge_by::incorrect' panicked at 'assertion failed: `x >= y` by `Ord::cmp`
+///      left: 5
+///     right: 6
+///  ordering: Less'
+/// ```
+#[macro_export]
+macro_rules! assert_ge_by {
+    ($left:expr, $right:expr, $compare:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let ordering = $crate::inequalities::compare_by(left_val, right_val, $compare);
+                if ordering == ::core::cmp::Ordering::Less {
+                    $crate::failure::fail("assert_ge_by", &$crate::report::Report::new(
+                        format_args!("`{} >= {}` by `{}`", stringify!($left), stringify!($right), stringify!($compare)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::other(format_args!("ordering"), format_args!("{:?}", ordering)),
+                        ],
+                    ).source(">=", &[stringify!($left), stringify!($right), stringify!($compare)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $compare:expr,) => ({
+        $crate::assert_ge_by!($left, $right, $compare)
+    });
+    ($left:expr, $right:expr, $compare:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let ordering = $crate::inequalities::compare_by(left_val, right_val, $compare);
+                if ordering == ::core::cmp::Ordering::Less {
+                    $crate::failure::fail("assert_ge_by", &$crate::report::Report::new(
+                        format_args!("`{} >= {}` by `{}`", stringify!($left), stringify!($right), stringify!($compare)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::other(format_args!("ordering"), format_args!("{:?}", ordering)),
+                        ],
+                    ).source(">=", &[stringify!($left), stringify!($right), stringify!($compare)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
+
+/// Asserts `(key(left) >= key(right))`.
+/// 
+/// ### Parameters
+/// 
+/// - `left` The left operand of the comparison.
+/// - `right` The right operand of the comparison.
+/// - `key` A function from `&left` and `&right` to the keys that are compared.
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - The keys must be at least [PartialOrd](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_ge_by_key;
+/// let x = ("b", 1);
+/// let y = ("a", 2);
+/// assert_ge_by_key!(y, x, |pair| pair.1)
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::ge_by_key::incorrect' panicked at 'assertion failed: `x >= y` by key `|pair| pair.1`
+///       left: ("a", 5)
+///      right: ("b", 6)
+///   left key: 5
+///  right key: 6'
+/// ```
+#[macro_export]
+macro_rules! assert_ge_by_key {
+    ($left:expr, $right:expr, $key:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::inequalities::compare_by_key(left_val, right_val, $key);
+                if !(left_key >= right_key) {
+                    $crate::failure::fail("assert_ge_by_key", &$crate::report::Report::new(
+                        format_args!("`{} >= {}` by key `{}`", stringify!($left), stringify!($right), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::actual(format_args!("left key"), format_args!("{:?}", left_key)),
+                            $crate::report::Field::expected(format_args!("right key"), format_args!("{:?}", right_key)),
+                        ],
+                    ).source(">=", &[stringify!($left), stringify!($right), stringify!($key)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, $key:expr,) => ({
+        $crate::assert_ge_by_key!($left, $right, $key)
+    });
+    ($left:expr, $right:expr, $key:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::inequalities::compare_by_key(left_val, right_val, $key);
+                if !(left_key >= right_key) {
+                    $crate::failure::fail("assert_ge_by_key", &$crate::report::Report::new(
+                        format_args!("`{} >= {}` by key `{}`", stringify!($left), stringify!($right), stringify!($key)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&*left_val))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&*right_val))),
+                            $crate::report::Field::actual(format_args!("left key"), format_args!("{:?}", left_key)),
+                            $crate::report::Field::expected(format_args!("right key"), format_args!("{:?}", right_key)),
+                        ],
+                    ).source(">=", &[stringify!($left), stringify!($right), stringify!($key)]).details(format_args!("{}", $crate::report::Diff(&*left_val, &*right_val))).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that two floating-point numbers are approximately equal.
+/// 
+/// ### Parameters
+/// 
+/// - `left` The left operand of the comparison.
+/// - `right` The right operand of the comparison.
+/// - `abs = tolerance` The largest allowed absolute difference, `|left - right|`, or
+///   `rel = tolerance` The largest allowed difference relative to the larger magnitude, `|left - right| / max(|left|, |right|)`, or
+///   `ulps = tolerance` The largest allowed number of representable values between `left` and `right`.
+/// 
+/// ### Dependencies
+/// 
+/// - `left` and `right` must both be [f32](https://doc.rust-lang.org/std/primitive.f32.html) or both be [f64](https://doc.rust-lang.org/std/primitive.f64.html).
+/// 
+/// `NaN` is never approximately equal to anything, and an infinity is only approximately equal to itself.
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_approx_eq;
+/// assert_approx_eq!(0.1 + 0.2, 0.3, abs = 1e-9);
+/// assert_approx_eq!(1_000_000.0, 1_000_000.5, rel = 1e-6);
+/// assert_approx_eq!(0.1_f32 + 0.2_f32, 0.3_f32, ulps = 4);
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::approx::abs_incorrect' panicked at 'assertion failed: `1.0 ≈ 1.1`
+///        left: 1.0
+///       right: 1.1
+///  difference: 0.10000000000000009 (absolute)
+///   tolerance: 1e-9'
+/// ```
+/// ```text
+/// thread 'inequalities::approx::nan' panicked at 'assertion failed: `f64::NAN ≈ 1.0`
+///       left: NaN
+///      right: 1.0
+///       note: left is NaN
+///  tolerance: 1e-9'
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr, abs = $tolerance:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let tolerance = $crate::inequalities::Tolerance::Abs($tolerance);
+                if let Some(detail) = $crate::inequalities::approx_eq(*left_val, *right_val, tolerance) {
+                    $crate::failure::fail("assert_approx_eq", &$crate::report::Report::new(
+                        format_args!("`{} ≈ {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*left_val)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*right_val)),
+                            $crate::report::Field::other(format_args!("{}", detail.label()), format_args!("{}", detail)),
+                            $crate::report::Field::other(format_args!("tolerance"), format_args!("{}", tolerance)),
+                        ],
+                    ).source("≈", &[stringify!($left), stringify!($right)]))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, abs = $tolerance:expr,) => ({
+        $crate::assert_approx_eq!($left, $right, abs = $tolerance)
+    });
+    ($left:expr, $right:expr, abs = $tolerance:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let tolerance = $crate::inequalities::Tolerance::Abs($tolerance);
+                if let Some(detail) = $crate::inequalities::approx_eq(*left_val, *right_val, tolerance) {
+                    $crate::failure::fail("assert_approx_eq", &$crate::report::Report::new(
+                        format_args!("`{} ≈ {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*left_val)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*right_val)),
+                            $crate::report::Field::other(format_args!("{}", detail.label()), format_args!("{}", detail)),
+                            $crate::report::Field::other(format_args!("tolerance"), format_args!("{}", tolerance)),
+                        ],
+                    ).source("≈", &[stringify!($left), stringify!($right)]).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, rel = $tolerance:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let tolerance = $crate::inequalities::Tolerance::Rel($tolerance);
+                if let Some(detail) = $crate::inequalities::approx_eq(*left_val, *right_val, tolerance) {
+                    $crate::failure::fail("assert_approx_eq", &$crate::report::Report::new(
+                        format_args!("`{} ≈ {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*left_val)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*right_val)),
+                            $crate::report::Field::other(format_args!("{}", detail.label()), format_args!("{}", detail)),
+                            $crate::report::Field::other(format_args!("tolerance"), format_args!("{}", tolerance)),
+                        ],
+                    ).source("≈", &[stringify!($left), stringify!($right)]))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, rel = $tolerance:expr,) => ({
+        $crate::assert_approx_eq!($left, $right, rel = $tolerance)
+    });
+    ($left:expr, $right:expr, rel = $tolerance:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let tolerance = $crate::inequalities::Tolerance::Rel($tolerance);
+                if let Some(detail) = $crate::inequalities::approx_eq(*left_val, *right_val, tolerance) {
+                    $crate::failure::fail("assert_approx_eq", &$crate::report::Report::new(
+                        format_args!("`{} ≈ {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*left_val)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*right_val)),
+                            $crate::report::Field::other(format_args!("{}", detail.label()), format_args!("{}", detail)),
+                            $crate::report::Field::other(format_args!("tolerance"), format_args!("{}", tolerance)),
+                        ],
+                    ).source("≈", &[stringify!($left), stringify!($right)]).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, ulps = $tolerance:expr) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let tolerance = $crate::inequalities::Tolerance::Ulps($tolerance);
+                if let Some(detail) = $crate::inequalities::approx_eq(*left_val, *right_val, tolerance) {
+                    $crate::failure::fail("assert_approx_eq", &$crate::report::Report::new(
+                        format_args!("`{} ≈ {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*left_val)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*right_val)),
+                            $crate::report::Field::other(format_args!("{}", detail.label()), format_args!("{}", detail)),
+                            $crate::report::Field::other(format_args!("tolerance"), format_args!("{}", tolerance)),
+                        ],
+                    ).source("≈", &[stringify!($left), stringify!($right)]))
+                }
+            }
+        }
+    });
+    ($left:expr, $right:expr, ulps = $tolerance:expr,) => ({
+        $crate::assert_approx_eq!($left, $right, ulps = $tolerance)
+    });
+    ($left:expr, $right:expr, ulps = $tolerance:expr, $($arg:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let tolerance = $crate::inequalities::Tolerance::Ulps($tolerance);
+                if let Some(detail) = $crate::inequalities::approx_eq(*left_val, *right_val, tolerance) {
+                    $crate::failure::fail("assert_approx_eq", &$crate::report::Report::new(
+                        format_args!("`{} ≈ {}`", stringify!($left), stringify!($right)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*left_val)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*right_val)),
+                            $crate::report::Field::other(format_args!("{}", detail.label()), format_args!("{}", detail)),
+                            $crate::report::Field::other(format_args!("tolerance"), format_args!("{}", tolerance)),
+                        ],
+                    ).source("≈", &[stringify!($left), stringify!($right)]).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a `range` contains a `value`.
+/// 
+/// ### Parameters
+/// 
+/// - `value` The value to look for.
+/// - `range` Any range, such as `1..10`, `1..=10`, `1..`, `..10` or `(Bound::Excluded(1), Bound::Unbounded)`.
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `range` must implement [RangeBounds](https://doc.rust-lang.org/std/ops/trait.RangeBounds.html) for the type of `value`.
+/// - `value` must be at least [PartialOrd](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_in_range;
+/// let x = 5;
+/// assert_in_range!(x, 1..=10);
+/// assert_in_range!(x, 1..);
+/// assert_in_range!(x, ..6);
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::messages::in_range' panicked at 'assertion failed: `x` in `1..=10`
+///   left: 11
+///  right: 1..=10
+///   note: left is greater than the inclusive end bound 10'
+/// ```
+#[macro_export]
+macro_rules! assert_in_range {
+    ($value:expr, $range:expr) => ({
+        match (&$value, &$range) {
+            (value, range) => {
+                if let Some(note) = $crate::inequalities::range_violation(value, range) {
+                    $crate::failure::fail("assert_in_range", &$crate::report::Report::new(
+                        format_args!("`{}` in `{}`", stringify!($value), stringify!($range)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*value)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*range)),
+                            $crate::report::Field::other(format_args!("note"), format_args!("{}", note)),
+                        ],
+                    ).source("in", &[stringify!($value), stringify!($range)]))
+                }
+            }
+        }
+    });
+    ($value:expr, $range:expr,) => ({
+        $crate::assert_in_range!($value, $range)
+    });
+    ($value:expr, $range:expr, $($arg:tt)+) => ({
+        match (&($value), &($range)) {
+            (value, range) => {
+                if let Some(note) = $crate::inequalities::range_violation(value, range) {
+                    $crate::failure::fail("assert_in_range", &$crate::report::Report::new(
+                        format_args!("`{}` in `{}`", stringify!($value), stringify!($range)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*value)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*range)),
+                            $crate::report::Field::other(format_args!("note"), format_args!("{}", note)),
+                        ],
+                    ).source("in", &[stringify!($value), stringify!($range)]).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a `range` does not contain a `value`.
+/// 
+/// ### Parameters
+/// 
+/// - `value` The value to look for.
+/// - `range` Any range, such as `1..10`, `1..=10`, `1..`, `..10` or `(Bound::Excluded(1), Bound::Unbounded)`.
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - `range` must implement [RangeBounds](https://doc.rust-lang.org/std/ops/trait.RangeBounds.html) for the type of `value`.
+/// - `value` must be at least [PartialOrd](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_not_in_range;
+/// let x = 10;
+/// assert_not_in_range!(x, 1..10);
+/// assert_not_in_range!(x, 11..);
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::not_in_range::inside' panicked at 'assertion failed: `x` not in `1..10`
+///   left: 5
+///  right: 1..10'
+/// ```
+#[macro_export]
+macro_rules! assert_not_in_range {
+    ($value:expr, $range:expr) => ({
+        match (&$value, &$range) {
+            (value, range) => {
+                if $crate::inequalities::range_violation(value, range).is_none() {
+                    $crate::failure::fail("assert_not_in_range", &$crate::report::Report::new(
+                        format_args!("`{}` not in `{}`", stringify!($value), stringify!($range)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*value)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*range)),
+                        ],
+                    ).source("not in", &[stringify!($value), stringify!($range)]))
+                }
+            }
+        }
+    });
+    ($value:expr, $range:expr,) => ({
+        $crate::assert_not_in_range!($value, $range)
+    });
+    ($value:expr, $range:expr, $($arg:tt)+) => ({
+        match (&($value), &($range)) {
+            (value, range) => {
+                if $crate::inequalities::range_violation(value, range).is_none() {
+                    $crate::failure::fail("assert_not_in_range", &$crate::report::Report::new(
+                        format_args!("`{}` not in `{}`", stringify!($value), stringify!($range)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", &*value)),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*range)),
+                        ],
+                    ).source("not in", &[stringify!($value), stringify!($range)]).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a chain of comparisons holds, such as `(low < x <= high)`.
+/// 
+/// ### Parameters
+/// 
+/// - `chain` Two or more operands separated by `<`, `<=`, `>`, `>=` or `==`.
+/// - `description` ***(optional)*** A format string, after a comma, describing the assertion.
+/// 
+/// Each operand is evaluated exactly once, from left to right, before any comparison is made.
+/// Operands are split at every top-level comparison operator, so an operand that contains
+/// `<` or `>` itself, such as `x.sum::<i32>()`, must be wrapped in parentheses.
+/// 
+/// ### Dependencies
+/// 
+/// - All content must implement [Debug](https://doc.rust-lang.org/std/fmt/trait.Debug.html)
+/// - Adjacent operands must be at least [PartialOrd](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html), or [PartialEq](https://doc.rust-lang.org/std/cmp/trait.PartialEq.html) for `==`.
+/// 
+/// ### Example
+///
+/// ```
+/// use totems::assert_ordered;
+/// let (low, x, high) = (1, 5, 10);
+/// assert_ordered!(low < x <= high);
+/// assert_ordered!(high > x >= x == 5 > low);
+/// assert_ordered!(0 <= x < (vec![1, 2].iter().sum::<i32>() * 3), "x is {}", x);
+/// ```
+/// 
+/// ### Example Error Messages
+/// 
+/// ```text
+/// thread 'inequalities::ordered::incorrect' panicked at 'assertion failed: `low < x <= high`
+///  link: `x <= high`
+///   low: 1
+///     x: 11
+///  high: 10'
+/// ```
+#[macro_export]
+macro_rules! assert_ordered {
+    (@parse [$($done:tt)*] [$($current:tt)+] <= $($rest:tt)*) => {
+        $crate::assert_ordered!(@parse [$($done)* ($($current)+) <=] [] $($rest)*)
+    };
+    (@parse [$($done:tt)*] [$($current:tt)+] >= $($rest:tt)*) => {
+        $crate::assert_ordered!(@parse [$($done)* ($($current)+) >=] [] $($rest)*)
+    };
+    (@parse [$($done:tt)*] [$($current:tt)+] == $($rest:tt)*) => {
+        $crate::assert_ordered!(@parse [$($done)* ($($current)+) ==] [] $($rest)*)
+    };
+    (@parse [$($done:tt)*] [$($current:tt)+] < $($rest:tt)*) => {
+        $crate::assert_ordered!(@parse [$($done)* ($($current)+) <] [] $($rest)*)
+    };
+    (@parse [$($done:tt)*] [$($current:tt)+] > $($rest:tt)*) => {
+        $crate::assert_ordered!(@parse [$($done)* ($($current)+) >] [] $($rest)*)
+    };
+    (@parse [] [$($current:tt)+] $(, $($arg:tt)+)?) => {
+        compile_error!("assert_ordered! needs at least one comparison, such as `a < b`")
+    };
+    (@parse [$($done:tt)+] [$($current:tt)+] , $($arg:tt)+) => {
+        $crate::assert_ordered!(@eval [] [] [] [$($arg)+] $($done)+ ($($current)+))
+    };
+    (@parse [$($done:tt)+] [$($current:tt)+]) => {
+        $crate::assert_ordered!(@eval [] [] [] [] $($done)+ ($($current)+))
+    };
+    (@parse [$($done:tt)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_ordered!(@parse [$($done)*] [$($current)* $next] $($rest)*)
+    };
+    (@eval [$($operands:tt)*] [$($ops:tt)*] [$($chain:tt)*] [$($arg:tt)*] ($($operand:tt)+) $op:tt $($rest:tt)+) => {
+        match &($($operand)+) {
+            value => $crate::assert_ordered!(@eval
+                [$($operands)* { value $($operand)+ }]
+                [$($ops)* $op]
+                [$($chain)* { value $($operand)+ } $op]
+                [$($arg)*]
+                $($rest)+
+            ),
+        }
+    };
+    (@eval [$($operands:tt)*] [$($ops:tt)*] [$($chain:tt)*] [$($arg:tt)*] ($($operand:tt)+)) => {
+        match &($($operand)+) {
+            value => $crate::assert_ordered!(@check 0usize;
+                [$($operands)* { value $($operand)+ }]
+                [$($ops)*]
+                [$($arg)*]
+                $($chain)* { value $($operand)+ }
+            ),
+        }
+    };
+    (@check $link:expr; $operands:tt $ops:tt $arg:tt { $left:ident $($l:tt)+ } $op:tt { $right:ident $($r:tt)+ } $($rest:tt)*) => {{
+        if !(*$left $op *$right) {
+            $crate::assert_ordered!(@fail $link; $operands $ops $arg)
+        }
+        $crate::assert_ordered!(@check $link + 1; $operands $ops $arg { $right $($r)+ } $($rest)*)
+    }};
+    (@check $link:expr; $operands:tt $ops:tt $arg:tt { $last:ident $($l:tt)+ }) => {
+        ()
+    };
+    (@fail $link:expr; [$({ $value:ident $($operand:tt)+ })+] [$($op:tt)+] []) => {{
+        let (operands, ops, link) = ([$(stringify!($($operand)+)),+], [$(stringify!($op)),+], $link);
+        $crate::failure::fail("assert_ordered", &$crate::report::Report::new(
+            format_args!("`{}`", $crate::inequalities::Chain { operands: &operands, ops: &ops }),
+            &[
+                $crate::report::Field::other(
+                    format_args!("link"),
+                    format_args!("`{}`", $crate::inequalities::Chain { operands: &operands[link..=link + 1], ops: &ops[link..=link] }),
+                ),
+                $($crate::report::Field::actual(format_args!("{}", stringify!($($operand)+)), format_args!("{:?}", $value)),)+
+            ],
+        ).source(ops[link], &operands[link..=link + 1]))
+    }};
+    (@fail $link:expr; [$({ $value:ident $($operand:tt)+ })+] [$($op:tt)+] [$($arg:tt)+]) => {{
+        let (operands, ops, link) = ([$(stringify!($($operand)+)),+], [$(stringify!($op)),+], $link);
+        $crate::failure::fail("assert_ordered", &$crate::report::Report::new(
+            format_args!("`{}`", $crate::inequalities::Chain { operands: &operands, ops: &ops }),
+            &[
+                $crate::report::Field::other(
+                    format_args!("link"),
+                    format_args!("`{}`", $crate::inequalities::Chain { operands: &operands[link..=link + 1], ops: &ops[link..=link] }),
+                ),
+                $($crate::report::Field::actual(format_args!("{}", stringify!($($operand)+)), format_args!("{:?}", $value)),)+
+            ],
+        ).source(ops[link], &operands[link..=link + 1]).message(format_args!($($arg)+)))
+    }};
+    ($($chain:tt)+) => {
+        $crate::assert_ordered!(@parse [] [] $($chain)+)
+    };
+}
+
+//=============================================================================================
+// Helpers
+//=============================================================================================
+
+/// Orders `left` and `right` with `compare`.
+///
+/// Taking `compare` as a parameter lets closures infer their argument types from the operands.
+#[doc(hidden)]
+pub fn compare_by<T, F>(left: &T, right: &T, compare: F) -> Ordering
+where
+    T: ?Sized,
+    F: FnOnce(&T, &T) -> Ordering,
+{
+    compare(left, right)
+}
+
+/// Extracts the keys of `left` and `right` to compare.
+#[doc(hidden)]
+pub fn compare_by_key<T, K, F>(left: &T, right: &T, mut key: F) -> (K, K)
+where
+    T: ?Sized,
+    F: FnMut(&T) -> K,
+{
+    (key(left), key(right))
+}
+
+/// A chain of comparisons, rendered by its `Display` implementation, e.g. `low < x <= high`.
+///
+/// `ops` holds one operator between each pair of adjacent `operands`.
+#[doc(hidden)]
+pub struct Chain<'a> {
+    pub operands: &'a [&'a str],
+    pub ops: &'a [&'a str],
+}
+
+impl fmt::Display for Chain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.operands[0])?;
+        for (operand, op) in self.operands[1..].iter().zip(self.ops) {
+            write!(f, " {} {}", op, operand)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks `value` against both bounds of `range`.
+///
+/// Returns `None` when `range` contains `value`, or the violated bound.
+#[doc(hidden)]
+pub fn range_violation<'a, T, R>(value: &T, range: &'a R) -> Option<RangeViolation<'a, T>>
+where
+    T: PartialOrd + fmt::Debug,
+    R: RangeBounds<T>,
+{
+    match range.start_bound() {
+        Bound::Included(start) | Bound::Excluded(start) if value.partial_cmp(start).is_none() => {
+            return Some(RangeViolation::StartIncomparable(start));
+        }
+        Bound::Included(start) if value.partial_cmp(start) == Some(Ordering::Less) => {
+            return Some(RangeViolation::BelowStart(start));
+        }
+        Bound::Excluded(start) if value.partial_cmp(start) != Some(Ordering::Greater) => {
+            return Some(RangeViolation::NotAboveStart(start));
+        }
+        _ => {}
+    }
+    match range.end_bound() {
+        Bound::Included(end) | Bound::Excluded(end) if value.partial_cmp(end).is_none() => {
+            Some(RangeViolation::EndIncomparable(end))
+        }
+        Bound::Included(end) if value.partial_cmp(end) == Some(Ordering::Greater) => {
+            Some(RangeViolation::AboveEnd(end))
+        }
+        Bound::Excluded(end) if value.partial_cmp(end) != Some(Ordering::Less) => {
+            Some(RangeViolation::NotBelowEnd(end))
+        }
+        _ => None,
+    }
+}
+
+/// The bound of a range that a value falls outside of.
+///
+/// Its `Display` implementation renders the note printed by [assert_in_range](../macro.assert_in_range.html).
+#[doc(hidden)]
+#[derive(Debug, PartialEq)]
+pub enum RangeViolation<'a, T> {
+    StartIncomparable(&'a T),
+    BelowStart(&'a T),
+    NotAboveStart(&'a T),
+    EndIncomparable(&'a T),
+    AboveEnd(&'a T),
+    NotBelowEnd(&'a T),
+}
+
+impl<T: fmt::Debug> fmt::Display for RangeViolation<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeViolation::StartIncomparable(start) => write!(f, "left is not comparable to the start bound {:?}", start),
+            RangeViolation::BelowStart(start) => write!(f, "left is less than the inclusive start bound {:?}", start),
+            RangeViolation::NotAboveStart(start) => write!(f, "left is not greater than the exclusive start bound {:?}", start),
+            RangeViolation::EndIncomparable(end) => write!(f, "left is not comparable to the end bound {:?}", end),
+            RangeViolation::AboveEnd(end) => write!(f, "left is greater than the inclusive end bound {:?}", end),
+            RangeViolation::NotBelowEnd(end) => write!(f, "left is not less than the exclusive end bound {:?}", end),
+        }
+    }
+}
+
+/// How far apart two floats may be in [assert_approx_eq](../macro.assert_approx_eq.html).
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub enum Tolerance<F> {
+    Abs(F),
+    Rel(F),
+    Ulps(u64),
+}
+
+/// The floating-point operations needed by [approx_eq](fn.approx_eq.html).
+#[doc(hidden)]
+pub trait Float: Copy + PartialOrd + fmt::Debug {
+    fn is_nan(self) -> bool;
+    fn is_infinite(self) -> bool;
+    fn abs_diff(self, other: Self) -> Self;
+    fn rel_diff(self, other: Self) -> Self;
+    fn to_f64(self) -> f64;
+    /// Returns `None` when `self` and `other` have different signs.
+    fn ulps_diff(self, other: Self) -> Option<u64>;
+}
+
+macro_rules! impl_float {
+    ($float:ty) => {
+        impl Float for $float {
+            fn is_nan(self) -> bool {
+                <$float>::is_nan(self)
+            }
+
+            fn is_infinite(self) -> bool {
+                <$float>::is_infinite(self)
+            }
+
+            fn abs_diff(self, other: Self) -> Self {
+                (self - other).abs()
+            }
+
+            fn rel_diff(self, other: Self) -> Self {
+                (self - other).abs() / self.abs().max(other.abs())
+            }
+
+            fn to_f64(self) -> f64 {
+                f64::from(self)
+            }
+
+            fn ulps_diff(self, other: Self) -> Option<u64> {
+                if self.is_sign_positive() != other.is_sign_positive() {
+                    return None;
+                }
+                let (a, b) = (u64::from(self.to_bits()), u64::from(other.to_bits()));
+                Some(if a > b { a - b } else { b - a })
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+/// Why two floats are not approximately equal.
+///
+/// Its `Display` implementation renders the value of the line named by [label](enum.Mismatch.html#method.label)
+/// in the message of [assert_approx_eq](../macro.assert_approx_eq.html).
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub enum Mismatch<F> {
+    Nan { left: bool, right: bool },
+    Infinite { left: bool, right: bool },
+    DifferentSigns,
+    Outside { difference: Tolerance<F>, tolerance: Tolerance<F> },
+}
+
+impl<F: Float> Mismatch<F> {
+    /// Names the line of the failure message that explains the mismatch.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Mismatch::Outside { .. } => "difference",
+            _ => "note",
+        }
+    }
+
+    /// Renders the mismatch on one line, without the tolerance.
+    #[cfg(feature = "alloc")]
+    pub fn brief(&self) -> String {
+        match *self {
+            Mismatch::Nan { .. } => String::from("NaN"),
+            Mismatch::Infinite { .. } => String::from("infinite"),
+            Mismatch::DifferentSigns => String::from("different signs"),
+            Mismatch::Outside { difference, .. } => alloc::format!("difference {}", difference),
+        }
+    }
+}
+
+impl<F: Float> fmt::Display for Mismatch<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn which(left: bool, right: bool) -> &'static str {
+            match (left, right) {
+                (true, true) => "left and right are",
+                (true, false) => "left is",
+                _ => "right is",
+            }
+        }
+        match *self {
+            Mismatch::Nan { left, right } => write!(f, "{} NaN", which(left, right)),
+            Mismatch::Infinite { left, right } => write!(f, "{} infinite", which(left, right)),
+            Mismatch::DifferentSigns => write!(f, "left and right have different signs"),
+            Mismatch::Outside { difference, .. } => write!(f, "{} ({})", difference, difference.kind()),
+        }
+    }
+}
+
+/// Renders the amount without its kind, e.g. `1e-9`.
+impl<F: Float> fmt::Display for Tolerance<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Tolerance::Abs(value) | Tolerance::Rel(value) => write!(f, "{:?}", value),
+            Tolerance::Ulps(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl<F: Float> Tolerance<F> {
+    /// Names the kind of tolerance, e.g. `absolute`.
+    pub fn kind(&self) -> &'static str {
+        match *self {
+            Tolerance::Abs(_) => "absolute",
+            Tolerance::Rel(_) => "relative",
+            Tolerance::Ulps(_) => "ulps",
+        }
+    }
+}
+
+/// Compares two floats within a `tolerance`.
+///
+/// Returns `None` when they are approximately equal.
+#[doc(hidden)]
+pub fn approx_eq<F: Float>(left: F, right: F, tolerance: Tolerance<F>) -> Option<Mismatch<F>> {
+    if left.is_nan() || right.is_nan() {
+        return Some(Mismatch::Nan { left: left.is_nan(), right: right.is_nan() });
+    }
+    if left == right {
+        return None;
+    }
+    if left.is_infinite() || right.is_infinite() {
+        return Some(Mismatch::Infinite { left: left.is_infinite(), right: right.is_infinite() });
+    }
+    let difference = match tolerance {
+        Tolerance::Abs(tolerance) => {
+            let difference = left.abs_diff(right);
+            if difference <= tolerance {
+                return None;
+            }
+            Tolerance::Abs(difference)
+        }
+        Tolerance::Rel(tolerance) => {
+            let difference = left.rel_diff(right);
+            if difference <= tolerance {
+                return None;
+            }
+            Tolerance::Rel(difference)
+        }
+        Tolerance::Ulps(tolerance) => match left.ulps_diff(right) {
+            Some(difference) if difference <= tolerance => return None,
+            Some(difference) => Tolerance::Ulps(difference),
+            None => return Some(Mismatch::DifferentSigns),
+        },
+    };
+    Some(Mismatch::Outside { difference, tolerance })
+}
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod lt {
+    #[test]
+    fn correct() {
+        assert_lt!(5, 7);
+        assert_lt!(5, 6);
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_lt!(5, 6,);
+    }
+    #[test]
+    fn format_string() {
+        let x = 5;
+        let y = 6;
+        assert_lt!(x, y, "{} is less than {}", x, y);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_lt!(5, 5);
+    }
+}
+
+#[cfg(test)]
+mod le {
+    #[test]
+    fn correct() {
+        assert_le!(5, 6);
+        assert_le!(5, 5);
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_le!(5, 6,);
+        assert_le!(5, 5,);
+    }
+    #[test]
+    fn format_string() {
+        let x = 5;
+        let y = 6;
+        assert_le!(x, y, "{} is less or equal to {}", x, y);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_le!(6, 5);
+    }
+}
+
+#[cfg(test)]
+mod gt {
+    #[test]
+    fn correct() {
+        assert_gt!(7, 5);
+        assert_gt!(6, 5);
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_gt!(7, 5,);
+        assert_gt!(6, 5,);
+    }
+    #[test]
+    fn format_string() {
+        let x = 7;
+        let y = 5;
+        assert_gt!(x, y, "{} is greater than {}", x, y);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_gt!(5, 5);
+    }
+}
+
+#[cfg(test)]
+mod ge {
+    #[test]
+    fn correct() {
+        assert_ge!(6, 6);
+        assert_ge!(5, 5);
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_ge!(6, 6,);
+        assert_ge!(5, 5,);
+    }
+    #[test]
+    fn format_string() {
+        let x = 6;
+        let y = 5;
+        assert_ge!(x, y, "{} is greater or equal to {}", x, y);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_ge!(5, 6);
+    }
+}
+
+#[cfg(test)]
+mod lt_by {
+    use std::cmp::Ordering;
+
+    #[derive(Debug)]
+    struct Task {
+        priority: u8,
+    }
+
+    #[test]
+    fn correct() {
+        assert_lt_by!(Task { priority: 4 }, Task { priority: 5 }, |x, y| x.priority.cmp(&y.priority));
+        assert_lt_by!(5, 4, |x: &i32, y: &i32| x.cmp(y).reverse());
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_lt_by!(4, 5, Ord::cmp,);
+    }
+    #[test]
+    fn format_string() {
+        let ordering = |_: &(), _: &()| Ordering::Less;
+        assert_lt_by!((), (), ordering, "{} is the only unit", "()");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_lt_by!(5, 5, Ord::cmp);
+    }
+}
+
+#[cfg(test)]
+mod lt_by_key {
+    #[test]
+    fn correct() {
+        assert_lt_by_key!(("b", 4), ("a", 5), |pair| pair.1);
+        assert_lt_by_key!("aaaa", "aaaaa", |s| s.len());
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_lt_by_key!(4, 5, |x| *x,);
+    }
+    #[test]
+    fn format_string() {
+        assert_lt_by_key!(("b", 4), ("a", 5), |pair| pair.1, "by the number, not the name");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_lt_by_key!(("a", 5), ("b", 5), |pair| pair.1);
+    }
+}
+
+#[cfg(test)]
+mod le_by {
+    use std::cmp::Ordering;
+
+    #[derive(Debug)]
+    struct Task {
+        priority: u8,
+    }
+
+    #[test]
+    fn correct() {
+        assert_le_by!(Task { priority: 4 }, Task { priority: 5 }, |x, y| x.priority.cmp(&y.priority));
+        assert_le_by!(5, 4, |x: &i32, y: &i32| x.cmp(y).reverse());
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_le_by!(4, 5, Ord::cmp,);
+    }
+    #[test]
+    fn format_string() {
+        let ordering = |_: &(), _: &()| Ordering::Equal;
+        assert_le_by!((), (), ordering, "{} is the only unit", "()");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_le_by!(6, 5, Ord::cmp);
+    }
+}
+
+#[cfg(test)]
+mod le_by_key {
+    #[test]
+    fn correct() {
+        assert_le_by_key!(("b", 4), ("a", 5), |pair| pair.1);
+        assert_le_by_key!("aaaa", "aaaaa", |s| s.len());
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_le_by_key!(4, 5, |x| *x,);
+    }
+    #[test]
+    fn format_string() {
+        assert_le_by_key!(("b", 4), ("a", 5), |pair| pair.1, "by the number, not the name");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_le_by_key!(("a", 6), ("b", 5), |pair| pair.1);
+    }
+}
+
+#[cfg(test)]
+mod gt_by {
+    use std::cmp::Ordering;
+
+    #[derive(Debug)]
+    struct Task {
+        priority: u8,
+    }
+
+    #[test]
+    fn correct() {
+        assert_gt_by!(Task { priority: 5 }, Task { priority: 4 }, |x, y| x.priority.cmp(&y.priority));
+        assert_gt_by!(4, 5, |x: &i32, y: &i32| x.cmp(y).reverse());
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_gt_by!(5, 4, Ord::cmp,);
+    }
+    #[test]
+    fn format_string() {
+        let ordering = |_: &(), _: &()| Ordering::Greater;
+        assert_gt_by!((), (), ordering, "{} is the only unit", "()");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_gt_by!(5, 5, Ord::cmp);
+    }
+}
+
+#[cfg(test)]
+mod gt_by_key {
+    #[test]
+    fn correct() {
+        assert_gt_by_key!(("b", 5), ("a", 4), |pair| pair.1);
+        assert_gt_by_key!("aaaaa", "aaaa", |s| s.len());
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_gt_by_key!(5, 4, |x| *x,);
+    }
+    #[test]
+    fn format_string() {
+        assert_gt_by_key!(("b", 5), ("a", 4), |pair| pair.1, "by the number, not the name");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_gt_by_key!(("a", 5), ("b", 5), |pair| pair.1);
+    }
+}
+
+#[cfg(test)]
+mod ge_by {
+    use std::cmp::Ordering;
+
+    #[derive(Debug)]
+    struct Task {
+        priority: u8,
+    }
+
+    #[test]
+    fn correct() {
+        assert_ge_by!(Task { priority: 5 }, Task { priority: 4 }, |x, y| x.priority.cmp(&y.priority));
+        assert_ge_by!(4, 5, |x: &i32, y: &i32| x.cmp(y).reverse());
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_ge_by!(5, 4, Ord::cmp,);
+    }
+    #[test]
+    fn format_string() {
+        let ordering = |_: &(), _: &()| Ordering::Equal;
+        assert_ge_by!((), (), ordering, "{} is the only unit", "()");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_ge_by!(5, 6, Ord::cmp);
+    }
+}
+
+#[cfg(test)]
+mod ge_by_key {
+    #[test]
+    fn correct() {
+        assert_ge_by_key!(("b", 5), ("a", 4), |pair| pair.1);
+        assert_ge_by_key!("aaaaa", "aaaa", |s| s.len());
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_ge_by_key!(5, 4, |x| *x,);
+    }
+    #[test]
+    fn format_string() {
+        assert_ge_by_key!(("b", 5), ("a", 4), |pair| pair.1, "by the number, not the name");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_ge_by_key!(("a", 5), ("b", 6), |pair| pair.1);
+    }
+}
+
+#[cfg(test)]
+mod approx {
+    use super::{approx_eq, Tolerance};
+
+    #[test]
+    fn abs_correct() {
+        assert_approx_eq!(0.1 + 0.2, 0.3, abs = 1e-9);
+        assert_approx_eq!(0.1_f32 + 0.2_f32, 0.3_f32, abs = 1e-6);
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_approx_eq!(0.1 + 0.2, 0.3, abs = 1e-9,);
+    }
+    #[test]
+    fn format_string() {
+        let x = 0.1 + 0.2;
+        assert_approx_eq!(x, 0.3, abs = 1e-9, "{} is about 0.3", x);
+    }
+    #[test]
+    #[should_panic]
+    fn abs_incorrect() {
+        assert_approx_eq!(1.0, 1.1, abs = 1e-9);
+    }
+    #[test]
+    fn rel_correct() {
+        assert_approx_eq!(1_000_000.0, 1_000_000.5, rel = 1e-6);
+        assert_approx_eq!(-1e-12, -1.0000001e-12, rel = 1e-6);
+    }
+    #[test]
+    #[should_panic]
+    fn rel_incorrect() {
+        assert_approx_eq!(1.0, 1.1, rel = 1e-6);
+    }
+    #[test]
+    fn ulps_correct() {
+        assert_approx_eq!(0.1_f32 + 0.2_f32, 0.3_f32, ulps = 4);
+        assert_approx_eq!(0.1 + 0.2, 0.3, ulps = 4);
+        assert_approx_eq!(0.0, -0.0, ulps = 0);
+    }
+    #[test]
+    #[should_panic]
+    fn ulps_incorrect() {
+        assert_approx_eq!(1.0, 1.0000001, ulps = 4);
+    }
+    #[test]
+    #[should_panic]
+    fn nan() {
+        assert_approx_eq!(f64::NAN, f64::NAN, abs = 1.0);
+    }
+    #[test]
+    fn infinite_correct() {
+        assert_approx_eq!(f64::INFINITY, f64::INFINITY, abs = 1e-9);
+    }
+    #[test]
+    #[should_panic]
+    fn infinite_incorrect() {
+        assert_approx_eq!(f64::INFINITY, f64::MAX, rel = 1.0);
+    }
+    #[test]
+    fn details() {
+        assert_eq!(approx_eq(1.0, 1.5, Tolerance::Abs(0.25)).unwrap().to_string(), "0.5 (absolute)");
+        assert_eq!(approx_eq(1.0, 2.0, Tolerance::Rel(0.25)).unwrap().to_string(), "0.5 (relative)");
+        assert_eq!(approx_eq(1.0_f32, 1.0000002, Tolerance::Ulps(1)).unwrap().to_string(), "2 (ulps)");
+        assert_eq!(approx_eq(-1.0, 1.0, Tolerance::Ulps(1)).unwrap().to_string(), "left and right have different signs");
+        assert_eq!(approx_eq(f64::NAN, 1.0, Tolerance::Abs(1.0)).unwrap().to_string(), "left is NaN");
+        assert_eq!(approx_eq(1.0, f64::NEG_INFINITY, Tolerance::Abs(1.0)).unwrap().to_string(), "right is infinite");
+        assert_eq!(approx_eq(1.0, 1.5, Tolerance::Abs(0.25)).unwrap().label(), "difference");
+        assert_eq!(approx_eq(f64::NAN, 1.0, Tolerance::Abs(1.0)).unwrap().label(), "note");
+        assert!(approx_eq(1.0, 1.0, Tolerance::Ulps(0)).is_none());
+    }
+}
+
+#[cfg(test)]
+mod in_range {
+    use super::range_violation;
+    use std::ops::Bound;
+
+    #[test]
+    fn correct() {
+        assert_in_range!(5, 1..10);
+        assert_in_range!(5, 1..=5);
+        assert_in_range!(5, 5..);
+        assert_in_range!(5, ..6);
+        assert_in_range!(5, ..);
+        assert_in_range!(5, (Bound::Excluded(4), Bound::Unbounded));
+        assert_in_range!(0.5, 0.0..1.0);
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_in_range!(5, 1..10,);
+    }
+    #[test]
+    fn format_string() {
+        let x = 5;
+        assert_in_range!(x, 1..10, "{} is a single digit", x);
+    }
+    #[test]
+    #[should_panic]
+    fn below() {
+        assert_in_range!(0, 1..10);
+    }
+    #[test]
+    #[should_panic]
+    fn above() {
+        assert_in_range!(10, 1..10);
+    }
+    #[test]
+    #[should_panic]
+    fn nan() {
+        assert_in_range!(f64::NAN, 0.0..1.0);
+    }
+    #[test]
+    fn notes() {
+        assert_eq!(range_violation(&0, &(1..10)).unwrap().to_string(), "left is less than the inclusive start bound 1");
+        assert_eq!(range_violation(&4, &(Bound::Excluded(4), Bound::Unbounded)).unwrap().to_string(), "left is not greater than the exclusive start bound 4");
+        assert_eq!(range_violation(&11, &(1..=10)).unwrap().to_string(), "left is greater than the inclusive end bound 10");
+        assert_eq!(range_violation(&10, &(..10)).unwrap().to_string(), "left is not less than the exclusive end bound 10");
+        assert_eq!(range_violation(&f64::NAN, &(..1.0)).unwrap().to_string(), "left is not comparable to the end bound 1.0");
+        assert_eq!(range_violation(&5, &(1..10)), None);
+    }
+}
+
+#[cfg(test)]
+mod not_in_range {
+    #[test]
+    fn correct() {
+        assert_not_in_range!(10, 1..10);
+        assert_not_in_range!(0, 1..=10);
+        assert_not_in_range!(5, 6..);
+        assert_not_in_range!(5, ..5);
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_not_in_range!(10, 1..10,);
+    }
+    #[test]
+    fn format_string() {
+        let x = 10;
+        assert_not_in_range!(x, 1..10, "{} is not a single digit", x);
+    }
+    #[test]
+    #[should_panic]
+    fn inside() {
+        assert_not_in_range!(5, 1..10);
+    }
+}
+
+#[cfg(test)]
+mod ordered {
+    use super::Chain;
+
+    #[test]
+    fn correct() {
+        let (low, x, high) = (1, 5, 10);
+        assert_ordered!(low < x <= high);
+        assert_ordered!(high > x >= x == 5 > low);
+        assert_ordered!(1 < 2);
+    }
+    #[test]
+    fn complex_operands() {
+        let vec = vec![1, 2, 3];
+        assert_ordered!(vec[0] < vec.len() as i32 <= (vec.iter().sum::<i32>()) == 6);
+        assert_ordered!(-1 < f(&vec, |x| x * 2) < 100);
+    }
+    #[test]
+    fn format_string() {
+        let x = 5;
+        assert_ordered!(0 <= x < 10, "{} is a single digit", x);
+    }
+    #[test]
+    fn evaluates_once() {
+        let mut calls = 0;
+        let mut next = || {
+            calls += 1;
+            calls
+        };
+        assert_ordered!(0 < next() < 5);
+        assert_eq!(calls, 1);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        let (low, x, high) = (1, 11, 10);
+        assert_ordered!(low < x <= high);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect_eq() {
+        assert_ordered!(1 < 2 == 3);
+    }
+    #[test]
+    fn chain() {
+        let (operands, ops) = (["low", "x", "high"], ["<", "<="]);
+        assert_eq!(Chain { operands: &operands, ops: &ops }.to_string(), "low < x <= high");
+        assert_eq!(Chain { operands: &operands[1..=2], ops: &ops[1..=1] }.to_string(), "x <= high");
+    }
+
+    fn f(vec: &[i32], g: impl Fn(i32) -> i32) -> i32 {
+        vec.iter().map(|&x| g(x)).sum()
+    }
+}
+
+#[cfg(test)]
+mod messages {
+    use crate::assert_panics;
+
+    #[test]
+    fn lt() {
+        assert_panics!({ assert_lt!(5, 5) }, message == "assertion failed: `5 < 5`\n  left: 5\n right: 5");
+        assert_panics!({ assert_lt!(5, 5, "{} is not less", 5) }, message == "assertion failed: `5 < 5`: 5 is not less\n  left: 5\n right: 5");
+    }
+
+    #[test]
+    fn le() {
+        assert_panics!({ assert_le!(6, 5) }, message == "assertion failed: `6 <= 5`\n  left: 6\n right: 5");
+        assert_panics!({ assert_le!(6, 5, "{} is greater", 6) }, message == "assertion failed: `6 <= 5`: 6 is greater\n  left: 6\n right: 5");
+    }
+
+    #[test]
+    fn gt() {
+        assert_panics!({ assert_gt!(5, 5) }, message == "assertion failed: `5 > 5`\n  left: 5\n right: 5");
+        assert_panics!({ assert_gt!(5, 5, "{} is not greater", 5) }, message == "assertion failed: `5 > 5`: 5 is not greater\n  left: 5\n right: 5");
+    }
+
+    #[test]
+    fn ge() {
+        assert_panics!({ assert_ge!(5, 6) }, message == "assertion failed: `5 >= 6`\n  left: 5\n right: 6");
+        assert_panics!({ assert_ge!(5, 6, "{} is less", 5) }, message == "assertion failed: `5 >= 6`: 5 is less\n  left: 5\n right: 6");
+    }
+
+    #[test]
+    fn lt_by() {
+        assert_panics!({ assert_lt_by!(5, 5, Ord::cmp) }, message == "assertion failed: `5 < 5` by `Ord::cmp`\n     left: 5\n    right: 5\n ordering: Equal");
+        assert_panics!({ assert_lt_by!(5, 5, Ord::cmp, "{}", "by comparator") }, message == "assertion failed: `5 < 5` by `Ord::cmp`: by comparator\n     left: 5\n    right: 5\n ordering: Equal");
+    }
+
+    #[test]
+    // The `diff` feature renders these multi-line values with `{:#?}`.
+    #[cfg(not(feature = "diff"))]
+    fn lt_by_key() {
+        assert_panics!({ assert_lt_by_key!(("a", 5), ("b", 5), |pair| pair.1) }, message == "assertion failed: `(\"a\", 5) < (\"b\", 5)` by key `|pair| pair.1`\n      left: (\"a\", 5)\n     right: (\"b\", 5)\n  left key: 5\n right key: 5");
+        assert_panics!({ assert_lt_by_key!(("a", 5), ("b", 5), |pair| pair.1, "{}", "by key") }, message == "assertion failed: `(\"a\", 5) < (\"b\", 5)` by key `|pair| pair.1`: by key\n      left: (\"a\", 5)\n     right: (\"b\", 5)\n  left key: 5\n right key: 5");
+    }
+
+    #[test]
+    fn le_by() {
+        assert_panics!({ assert_le_by!(6, 5, Ord::cmp) }, message == "assertion failed: `6 <= 5` by `Ord::cmp`\n     left: 6\n    right: 5\n ordering: Greater");
+        assert_panics!({ assert_le_by!(6, 5, Ord::cmp, "{}", "by comparator") }, message == "assertion failed: `6 <= 5` by `Ord::cmp`: by comparator\n     left: 6\n    right: 5\n ordering: Greater");
+    }
+
+    #[test]
+    // The `diff` feature renders these multi-line values with `{:#?}`.
+    #[cfg(not(feature = "diff"))]
+    fn le_by_key() {
+        assert_panics!({ assert_le_by_key!(("a", 6), ("b", 5), |pair| pair.1) }, message == "assertion failed: `(\"a\", 6) <= (\"b\", 5)` by key `|pair| pair.1`\n      left: (\"a\", 6)\n     right: (\"b\", 5)\n  left key: 6\n right key: 5");
+        assert_panics!({ assert_le_by_key!(("a", 6), ("b", 5), |pair| pair.1, "{}", "by key") }, message == "assertion failed: `(\"a\", 6) <= (\"b\", 5)` by key `|pair| pair.1`: by key\n      left: (\"a\", 6)\n     right: (\"b\", 5)\n  left key: 6\n right key: 5");
+    }
+
+    #[test]
+    fn gt_by() {
+        assert_panics!({ assert_gt_by!(5, 5, Ord::cmp) }, message == "assertion failed: `5 > 5` by `Ord::cmp`\n     left: 5\n    right: 5\n ordering: Equal");
+        assert_panics!({ assert_gt_by!(5, 5, Ord::cmp, "{}", "by comparator") }, message == "assertion failed: `5 > 5` by `Ord::cmp`: by comparator\n     left: 5\n    right: 5\n ordering: Equal");
+    }
+
+    #[test]
+    // The `diff` feature renders these multi-line values with `{:#?}`.
+    #[cfg(not(feature = "diff"))]
+    fn gt_by_key() {
+        assert_panics!({ assert_gt_by_key!(("a", 5), ("b", 5), |pair| pair.1) }, message == "assertion failed: `(\"a\", 5) > (\"b\", 5)` by key `|pair| pair.1`\n      left: (\"a\", 5)\n     right: (\"b\", 5)\n  left key: 5\n right key: 5");
+        assert_panics!({ assert_gt_by_key!(("a", 5), ("b", 5), |pair| pair.1, "{}", "by key") }, message == "assertion failed: `(\"a\", 5) > (\"b\", 5)` by key `|pair| pair.1`: by key\n      left: (\"a\", 5)\n     right: (\"b\", 5)\n  left key: 5\n right key: 5");
+    }
+
+    #[test]
+    fn ge_by() {
+        assert_panics!({ assert_ge_by!(5, 6, Ord::cmp) }, message == "assertion failed: `5 >= 6` by `Ord::cmp`\n     left: 5\n    right: 6\n ordering: Less");
+        assert_panics!({ assert_ge_by!(5, 6, Ord::cmp, "{}", "by comparator") }, message == "assertion failed: `5 >= 6` by `Ord::cmp`: by comparator\n     left: 5\n    right: 6\n ordering: Less");
+    }
+
+    #[test]
+    // The `diff` feature renders these multi-line values with `{:#?}`.
+    #[cfg(not(feature = "diff"))]
+    fn ge_by_key() {
+        assert_panics!({ assert_ge_by_key!(("a", 5), ("b", 6), |pair| pair.1) }, message == "assertion failed: `(\"a\", 5) >= (\"b\", 6)` by key `|pair| pair.1`\n      left: (\"a\", 5)\n     right: (\"b\", 6)\n  left key: 5\n right key: 6");
+        assert_panics!({ assert_ge_by_key!(("a", 5), ("b", 6), |pair| pair.1, "{}", "by key") }, message == "assertion failed: `(\"a\", 5) >= (\"b\", 6)` by key `|pair| pair.1`: by key\n      left: (\"a\", 5)\n     right: (\"b\", 6)\n  left key: 5\n right key: 6");
+    }
+
+    #[test]
+    fn approx_eq() {
+        assert_panics!({ assert_approx_eq!(1.0, 1.5, abs = 0.1) }, message == "assertion failed: `1.0 ≈ 1.5`\n       left: 1.0\n      right: 1.5\n difference: 0.5 (absolute)\n  tolerance: 0.1");
+        assert_panics!({ assert_approx_eq!(1.0, 1.5, abs = 0.1, "{}", "absolute") }, message == "assertion failed: `1.0 ≈ 1.5`: absolute\n       left: 1.0\n      right: 1.5\n difference: 0.5 (absolute)\n  tolerance: 0.1");
+        assert_panics!({ assert_approx_eq!(100.0, 110.0, rel = 0.05) }, message == "assertion failed: `100.0 ≈ 110.0`\n       left: 100.0\n      right: 110.0\n difference: 0.09090909090909091 (relative)\n  tolerance: 0.05");
+        assert_panics!({ assert_approx_eq!(100.0, 110.0, rel = 0.05, "{}", "relative") }, message == "assertion failed: `100.0 ≈ 110.0`: relative\n       left: 100.0\n      right: 110.0\n difference: 0.09090909090909091 (relative)\n  tolerance: 0.05");
+        assert_panics!({ assert_approx_eq!(1.0_f32, 1.0001_f32, ulps = 4) }, message == "assertion failed: `1.0_f32 ≈ 1.0001_f32`\n       left: 1.0\n      right: 1.0001\n difference: 839 (ulps)\n  tolerance: 4");
+        assert_panics!({ assert_approx_eq!(1.0_f32, 1.0001_f32, ulps = 4, "{}", "ulps") }, message == "assertion failed: `1.0_f32 ≈ 1.0001_f32`: ulps\n       left: 1.0\n      right: 1.0001\n difference: 839 (ulps)\n  tolerance: 4");
+        assert_panics!({ assert_approx_eq!(f64::NAN, 1.0, abs = 0.1) }, message == "assertion failed: `f64::NAN ≈ 1.0`\n      left: NaN\n     right: 1.0\n      note: left is NaN\n tolerance: 0.1");
+        assert_panics!({ assert_approx_eq!(1.0, f64::INFINITY, rel = 0.1) }, message == "assertion failed: `1.0 ≈ f64::INFINITY`\n      left: 1.0\n     right: inf\n      note: right is infinite\n tolerance: 0.1");
+        assert_panics!({ assert_approx_eq!(1.0, -1.0, ulps = 1) }, message == "assertion failed: `1.0 ≈ -1.0`\n      left: 1.0\n     right: -1.0\n      note: left and right have different signs\n tolerance: 1");
+    }
+
+    #[test]
+    fn in_range() {
+        assert_panics!({ assert_in_range!(0, 1..=10) }, message == "assertion failed: `0` in `1..=10`\n  left: 0\n right: 1..=10\n  note: left is less than the inclusive start bound 1");
+        assert_panics!({ assert_in_range!(10, 1..10) }, message == "assertion failed: `10` in `1..10`\n  left: 10\n right: 1..10\n  note: left is not less than the exclusive end bound 10");
+        assert_panics!({ assert_in_range!(11, 1..=10) }, message == "assertion failed: `11` in `1..=10`\n  left: 11\n right: 1..=10\n  note: left is greater than the inclusive end bound 10");
+        assert_panics!({ assert_in_range!(5, (std::ops::Bound::Excluded(5), std::ops::Bound::Unbounded)) }, message == "assertion failed: `5` in `(std::ops::Bound::Excluded(5), std::ops::Bound::Unbounded)`\n  left: 5\n right: (Excluded(5), Unbounded)\n  note: left is not greater than the exclusive start bound 5");
+        assert_panics!({ assert_in_range!(0.5, 1.0..) }, message == "assertion failed: `0.5` in `1.0..`\n  left: 0.5\n right: 1.0..\n  note: left is less than the inclusive start bound 1.0");
+        assert_panics!({ assert_in_range!(f64::NAN, 0.0..1.0) }, message == "assertion failed: `f64::NAN` in `0.0..1.0`\n  left: NaN\n right: 0.0..1.0\n  note: left is not comparable to the start bound 0.0");
+        assert_panics!({ assert_in_range!(0, 1..=10, "{} is out", 0) }, message == "assertion failed: `0` in `1..=10`: 0 is out\n  left: 0\n right: 1..=10\n  note: left is less than the inclusive start bound 1");
+    }
+
+    #[test]
+    fn not_in_range() {
+        assert_panics!({ assert_not_in_range!(5, 1..=10) }, message == "assertion failed: `5` not in `1..=10`\n  left: 5\n right: 1..=10");
+        assert_panics!({ assert_not_in_range!(5, ..) }, message == "assertion failed: `5` not in `..`\n  left: 5\n right: ..");
+        assert_panics!({ assert_not_in_range!(5, 1..=10, "{} is in", 5) }, message == "assertion failed: `5` not in `1..=10`: 5 is in\n  left: 5\n right: 1..=10");
+    }
+
+    #[test]
+    fn ordered() {
+        let (low, x, high) = (1, 11, 10);
+        assert_panics!({ assert_ordered!(low < x <= high) }, message == "assertion failed: `low < x <= high`\n link: `x <= high`\n  low: 1\n    x: 11\n high: 10");
+        assert_panics!({ assert_ordered!(high > x) }, message == "assertion failed: `high > x`\n link: `high > x`\n high: 10\n    x: 11");
+        assert_panics!({ assert_ordered!(low == x >= high) }, message == "assertion failed: `low == x >= high`\n link: `low == x`\n  low: 1\n    x: 11\n high: 10");
+        assert_panics!({ assert_ordered!(low < x <= high, "{} is too big", x) }, message == "assertion failed: `low < x <= high`: 11 is too big\n link: `x <= high`\n  low: 1\n    x: 11\n high: 10");
     }
 }
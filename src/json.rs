@@ -0,0 +1,231 @@
+use crate::failure::Failure;
+use crate::report::Role;
+use core::fmt::{self, Write};
+
+//=============================================================================================
+// Records
+//=============================================================================================
+
+/// A failed assertion, rendered as a single line of JSON for tools that read test output.
+///
+/// With the `json` feature, every failed assertion writes its record to standard error before
+/// it panics. Without it, a [failure handler](../fn.set_failure_handler.html) can render records
+/// itself. A record looks like:
+///
+/// ```text
+/// {"assertion":"assert_lt","file":"src/lib.rs","line":3,"column":1,"operator":"<","expressions":["x","y"],"values":[{"label":"left","value":"5","role":"actual"},{"label":"right","value":"4","role":"expected"}],"details":null,"message":null}
+/// ```
+///
+/// `expressions` holds the source of each operand, and `values` the labelled values of the
+/// failure message, rendered as they are in the message. `details` holds the lines after the
+/// values, like the diff of [assert_seq_eq](../macro.assert_seq_eq.html) or the diagram of
+/// [check](../macro.check.html), or `null`. `message` is the message passed to the macro, or `null`.
+///
+/// ### Example
+///
+/// ```
+/// use totems::{assert_lt, set_failure_handler, Failure};
+/// use totems::json::Record;
+///
+/// fn log(failure: &Failure) {
+///     eprintln!("{}", Record(failure));
+/// }
+///
+/// let previous = set_failure_handler(log);
+/// let result = std::panic::catch_unwind(|| assert_lt!(2, 1));
+/// set_failure_handler(previous);
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Record<'a>(pub &'a Failure<'a>);
+
+impl fmt::Display for Record<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Failure { assertion, location, report } = *self.0;
+        f.write_str("{\"assertion\":")?;
+        string(f, assertion)?;
+        f.write_str(",\"file\":")?;
+        string(f, location.file())?;
+        write!(f, ",\"line\":{},\"column\":{}", location.line(), location.column())?;
+        f.write_str(",\"operator\":")?;
+        string(f, report.operator)?;
+        f.write_str(",\"expressions\":[")?;
+        for (i, expression) in report.expressions.iter().enumerate() {
+            if i > 0 {
+                f.write_char(',')?;
+            }
+            string(f, expression)?;
+        }
+        f.write_str("],\"values\":[")?;
+        for (i, field) in report.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_char(',')?;
+            }
+            f.write_str("{\"label\":")?;
+            string(f, field.label)?;
+            f.write_str(",\"value\":")?;
+            string(f, field.value)?;
+            f.write_str(",\"role\":")?;
+            string(f, match field.role {
+                Role::Actual => "actual",
+                Role::Expected => "expected",
+                Role::Other => "other",
+            })?;
+            f.write_char('}')?;
+        }
+        f.write_str("],\"details\":")?;
+        match report.details {
+            Some(details) => {
+                f.write_char('"')?;
+                let mut trimmed = TrimEnd { inner: Escaped(f), newlines: 0 };
+                write!(trimmed, "{}", details)?;
+                f.write_char('"')?;
+            }
+            None => f.write_str("null")?,
+        }
+        f.write_str(",\"message\":")?;
+        match report.message {
+            Some(message) => string(f, message)?,
+            None => f.write_str("null")?,
+        }
+        f.write_char('}')
+    }
+}
+
+/// Writes `value` as a JSON string.
+fn string(f: &mut fmt::Formatter<'_>, value: impl fmt::Display) -> fmt::Result {
+    f.write_char('"')?;
+    write!(Escaped(f), "{}", value)?;
+    f.write_char('"')
+}
+
+/// Escapes everything written through it for a JSON string, which keeps a record on one line.
+struct Escaped<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+impl Write for Escaped<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut plain = 0;
+        for (i, c) in s.char_indices() {
+            let escape = match c {
+                '"' => Some("\\\""),
+                '\\' => Some("\\\\"),
+                '\n' => Some("\\n"),
+                '\r' => Some("\\r"),
+                '\t' => Some("\\t"),
+                c if c.is_control() => None,
+                _ => continue,
+            };
+            self.0.write_str(&s[plain..i])?;
+            match escape {
+                Some(escape) => self.0.write_str(escape)?,
+                None => write!(self.0, "\\u{:04x}", c as u32)?,
+            }
+            plain = i + c.len_utf8();
+        }
+        self.0.write_str(&s[plain..])
+    }
+}
+
+/// Drops the newlines at the end of everything written through it, as a rendered report does.
+struct TrimEnd<W> {
+    inner: W,
+    newlines: usize,
+}
+
+impl<W: Write> Write for TrimEnd<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let text = s.trim_end_matches('\n');
+        if !text.is_empty() {
+            for _ in 0..self.newlines {
+                self.inner.write_char('\n')?;
+            }
+            self.inner.write_str(text)?;
+            self.newlines = 0;
+        }
+        self.newlines += s.len() - text.len();
+        Ok(())
+    }
+}
+
+/// Writes the record of `failure` to standard error, unless
+/// [assert_panics](../macro.assert_panics.html) expects the failure.
+#[cfg(feature = "json")]
+pub(crate) fn emit(failure: &Failure<'_>) {
+    if !crate::panics::catching() {
+        std::eprintln!("{}", Record(failure));
+    }
+}
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod record {
+    use super::Record;
+    use crate::failure::Failure;
+    use crate::report::{Field, Report};
+    use core::panic::Location;
+
+    #[test]
+    fn fields() {
+        let location = Location::caller();
+        let rendered = Record(&Failure {
+            assertion: "assert_lt",
+            location,
+            report: &Report::new(
+                format_args!("`x < y`"),
+                &[
+                    Field::actual(format_args!("left"), format_args!("{}", 5)),
+                    Field::expected(format_args!("right"), format_args!("{}", 4)),
+                    Field::other(format_args!("note"), format_args!("none")),
+                ],
+            )
+            .source("<", &["x", "y"]),
+        })
+        .to_string();
+        assert_eq!(
+            rendered,
+            format!(
+                "{{\"assertion\":\"assert_lt\",\"file\":\"{}\",\"line\":{},\"column\":{},\"operator\":\"<\",\
+                 \"expressions\":[\"x\",\"y\"],\"values\":[{{\"label\":\"left\",\"value\":\"5\",\"role\":\"actual\"}},\
+                 {{\"label\":\"right\",\"value\":\"4\",\"role\":\"expected\"}},{{\"label\":\"note\",\"value\":\"none\",\"role\":\"other\"}}],\
+                 \"details\":null,\"message\":null}}",
+                location.file(),
+                location.line(),
+                location.column(),
+            ),
+        );
+    }
+    #[test]
+    fn escaped() {
+        let rendered = Record(&Failure {
+            assertion: "check",
+            location: Location::caller(),
+            report: &Report::new(format_args!(""), &[Field::actual(format_args!("value"), format_args!("{:?}", "a\"b"))])
+                .source("", &["a\n\tb\\c\u{1b}"])
+                .message(format_args!("é {}", '\r')),
+        })
+        .to_string();
+        assert!(rendered.contains(r#""expressions":["a\n\tb\\c\u001b"]"#), "{}", rendered);
+        assert!(rendered.contains(r#""value":"\"a\\\"b\"""#), "{}", rendered);
+        assert!(rendered.ends_with(r#""message":"é \r"}"#), "{}", rendered);
+        assert!(!rendered.contains('\n'));
+    }
+    #[test]
+    fn empty() {
+        let rendered = Record(&Failure { assertion: "check", location: Location::caller(), report: &Report::new(format_args!(""), &[]) })
+            .to_string();
+        assert!(rendered.contains(r#""operator":"","expressions":[],"values":[],"details":null,"message":null}"#), "{}", rendered);
+    }
+    #[test]
+    fn details() {
+        let rendered = Record(&Failure {
+            assertion: "assert_seq_eq",
+            location: Location::caller(),
+            report: &Report::new(format_args!("`a == b`"), &[]).details(format_args!("-[1]: {}\n{}\n\n", 2, "+[1]: 3")),
+        })
+        .to_string();
+        assert!(rendered.contains(r#""values":[],"details":"-[1]: 2\n+[1]: 3","message":null}"#), "{}", rendered);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::failure::Failure;
+use std::ffi::OsStr;
+use std::fmt::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::{env, fs, io, process, thread};
+
+//=============================================================================================
+// Collection
+//=============================================================================================
+
+/// The environment variable that turns on JUnit reports, naming the directory they are written to.
+///
+/// With the `junit` feature, when `TOTEMS_JUNIT` is set, every failed assertion of a test binary
+/// is added to `<directory>/<binary name>-<process id>.xml`, a JUnit report with one test case
+/// per failed assertion. Each process writes its own report, so binaries that run at the same
+/// time, or a binary that runs again, never overwrite each other's failures. The report is
+/// rewritten after each failure, so it is complete even when the binary aborts. Failures that [assert_panics](../macro.assert_panics.html) expects are left out,
+/// but those expected some other way, e.g. by `#[should_panic]` or `catch_unwind`, are reported.
+///
+/// ```text
+/// TOTEMS_JUNIT=target/junit cargo test --features totems/junit
+/// ```
+///
+/// Only failures are reported, since totems never sees the tests that pass.
+pub const VARIABLE: &str = "TOTEMS_JUNIT";
+
+/// The rendered test cases of this process.
+static CASES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Adds `failure` to the report of this test binary, if reports are turned on.
+pub(crate) fn collect(failure: &Failure<'_>) {
+    let directory = match env::var_os(VARIABLE) {
+        Some(directory) if !directory.is_empty() && !crate::panics::catching() => PathBuf::from(directory),
+        _ => return,
+    };
+    let suite = suite();
+    let current = thread::current();
+    let case = testcase(current.name().unwrap_or("main"), failure);
+    record(&directory, &suite, process::id(), &CASES, case);
+}
+
+/// Adds `case` to `cases` and rewrites the report of process `id` in `directory` with them.
+fn record(directory: &Path, suite: &str, id: u32, cases: &Mutex<Vec<String>>, case: String) {
+    let mut cases = cases.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    cases.push(case);
+    let path = directory.join(format!("{}-{}.xml", suite, id));
+    if let Err(error) = write(&path, &document(suite, &cases)) {
+        eprintln!("totems: could not write the JUnit report {}: {}", path.display(), error);
+    }
+}
+
+/// The name of the test binary, like `totems-0123456789abcdef`.
+fn suite() -> String {
+    env::current_exe()
+        .ok()
+        .as_deref()
+        .and_then(Path::file_stem)
+        .and_then(OsStr::to_str)
+        .unwrap_or("totems")
+        .into()
+}
+
+fn write(path: &Path, document: &str) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(path, document)
+}
+
+//=============================================================================================
+// Rendering
+//=============================================================================================
+
+/// Renders a failed assertion as a `<testcase>`, named after the test that ran it.
+///
+/// The failure's message is the first line of the report, and its text is the whole report with
+/// the location of the assertion.
+fn testcase(test: &str, failure: &Failure<'_>) -> String {
+    let report = failure.report.to_string();
+    let first = report.lines().next().unwrap_or_default();
+    let location = failure.location;
+    let mut case = String::new();
+    let _ = write!(
+        case,
+        "    <testcase name=\"{}\" classname=\"{}\" file=\"{}\" line=\"{}\">\n      \
+         <failure type=\"{}\" message=\"{}\">{}\n at {}</failure>\n    </testcase>\n",
+        Xml(test),
+        Xml(test.rsplit_once("::").map_or(test, |(module, _)| module)),
+        Xml(location.file()),
+        location.line(),
+        Xml(failure.assertion),
+        Xml(first),
+        Xml(&report),
+        Xml(location),
+    );
+    case
+}
+
+/// Renders a report with the test cases of one test binary.
+fn document(suite: &str, cases: &[String]) -> String {
+    let mut document = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = write!(
+        document,
+        "<testsuites name=\"totems\" tests=\"{count}\" failures=\"{count}\">\n  \
+         <testsuite name=\"{}\" tests=\"{count}\" failures=\"{count}\">\n",
+        Xml(suite),
+        count = cases.len(),
+    );
+    for case in cases {
+        document.push_str(case);
+    }
+    document.push_str("  </testsuite>\n</testsuites>\n");
+    document
+}
+
+/// Renders a value as XML text, which is also safe within quoted attributes.
+///
+/// Characters that XML 1.0 cannot hold, like most control characters, become `U+FFFD`.
+struct Xml<T>(T);
+
+impl<T: fmt::Display> fmt::Display for Xml<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(Escaped(f), "{}", self.0)
+    }
+}
+
+struct Escaped<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+impl fmt::Write for Escaped<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut plain = 0;
+        for (i, c) in s.char_indices() {
+            let escape = match c {
+                '&' => "&amp;",
+                '<' => "&lt;",
+                '>' => "&gt;",
+                '"' => "&quot;",
+                '\'' => "&apos;",
+                '\n' | '\r' | '\t' => continue,
+                c if c.is_control() || c == '\u{fffe}' || c == '\u{ffff}' => "\u{fffd}",
+                _ => continue,
+            };
+            self.0.write_str(&s[plain..i])?;
+            self.0.write_str(escape)?;
+            plain = i + c.len_utf8();
+        }
+        self.0.write_str(&s[plain..])
+    }
+}
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod rendering {
+    use super::{document, testcase, Xml};
+    use crate::failure::Failure;
+    use crate::report::{Field, Report};
+    use std::panic::Location;
+
+    #[test]
+    fn escaped() {
+        assert_eq!(Xml("a < b && \"c\" > 'd'").to_string(), "a &lt; b &amp;&amp; &quot;c&quot; &gt; &apos;d&apos;");
+        assert_eq!(Xml("line\n\tnext\u{1b}[31m").to_string(), "line\n\tnext\u{fffd}[31m");
+    }
+    #[test]
+    fn case() {
+        let location = Location::caller();
+        let case = testcase(
+            "inequalities::lt::incorrect",
+            &Failure {
+                assertion: "assert_lt",
+                location,
+                report: &Report::new(
+                    format_args!("`x < y`"),
+                    &[
+                        Field::actual(format_args!("left"), format_args!("{}", 5)),
+                        Field::expected(format_args!("right"), format_args!("{}", 4)),
+                    ],
+                ),
+            },
+        );
+        assert_eq!(
+            case,
+            format!(
+                "    <testcase name=\"inequalities::lt::incorrect\" classname=\"inequalities::lt\" file=\"{file}\" line=\"{line}\">\n      \
+                 <failure type=\"assert_lt\" message=\"assertion failed: `x &lt; y`\">assertion failed: `x &lt; y`\n  left: 5\n right: 4\n \
+                 at {file}:{line}:{column}</failure>\n    </testcase>\n",
+                file = location.file(),
+                line = location.line(),
+                column = location.column(),
+            ),
+        );
+    }
+    #[test]
+    fn suite() {
+        let cases = [String::from("    <testcase/>\n"), String::from("    <testcase/>\n")];
+        assert_eq!(
+            document("totems-0123", &cases),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites name=\"totems\" tests=\"2\" failures=\"2\">\n  \
+             <testsuite name=\"totems-0123\" tests=\"2\" failures=\"2\">\n    \
+             <testcase/>\n    <testcase/>\n  \
+             </testsuite>\n</testsuites>\n",
+        );
+    }
+}
+
+#[cfg(test)]
+mod collection {
+    use super::record;
+    use std::sync::Mutex;
+    use std::{env, fs, process};
+
+    #[test]
+    fn one_report_per_process() {
+        let directory = env::temp_dir().join(format!("totems-junit-{}", process::id()));
+        let (first, second) = (Mutex::new(Vec::new()), Mutex::new(Vec::new()));
+        record(&directory, "suite", 1, &first, String::from("    <testcase name=\"first\"/>\n"));
+        record(&directory, "suite", 2, &second, String::from("    <testcase name=\"second\"/>\n"));
+        let first = fs::read_to_string(directory.join("suite-1.xml")).unwrap();
+        let second = fs::read_to_string(directory.join("suite-2.xml")).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+        assert!(first.contains("<testcase name=\"first\"/>") && !first.contains("second"));
+        assert!(second.contains("<testcase name=\"second\"/>") && !second.contains("first"));
+    }
+}
@@ -1,4 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod failure;
+pub mod report;
+pub mod json;
 pub mod enums;
 pub mod tuples;
 pub mod collections;
-pub mod inequalities;
\ No newline at end of file
+pub mod inequalities;
+#[cfg(feature = "std")]
+pub mod time;
+#[cfg(feature = "std")]
+pub mod panics;
+#[cfg(feature = "junit")]
+pub mod junit;
+#[cfg(feature = "alloc")]
+pub mod check;
+mod debug;
+
+pub use crate::failure::{Failure, FailureHandler};
+#[cfg(feature = "std")]
+pub use crate::failure::{failure_handler, set_failure_handler, set_thread_failure_handler};
@@ -0,0 +1,385 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+//=============================================================================================
+// Macros
+//=============================================================================================
+
+/// Asserts that a block of code panics.
+///
+/// The panic is caught with [catch_unwind](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html),
+/// and the panic hook stays quiet while the block runs on the current thread, so that expected
+/// panics do not clutter test output.
+///
+/// ### Parameters
+///
+/// - `block` The code that should panic.
+/// - `message == expected` ***(optional)*** Asserts that the panic message equals `expected`.
+/// - `message contains pattern` ***(optional)*** Asserts that the panic message contains `pattern`.
+/// - `payload is Type` ***(optional)*** Asserts that the panic payload is a `Type`.
+///
+/// ### Returns
+///
+/// - The payload as a `Box<dyn Any + Send>` without arms.
+/// - The panic message as a `String` with the `message` arms.
+/// - The payload as a `Type` with the `payload is Type` arm.
+///
+/// ### Dependencies
+///
+/// - Panic messages are the payloads of `panic!`: a `&str` or a `String`.
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_panics;
+/// let v: Vec<u32> = Vec::new();
+/// assert_panics!({ v[0] });
+/// assert_panics!({ v[0] }, message contains "out of bounds");
+/// assert_panics!({ panic!("oh {}", "no") }, message == "oh no");
+/// let code = assert_panics!({ std::panic::panic_any(42) }, payload is i32);
+/// assert_eq!(code, 42);
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'panics::blocks::incorrect' panicked at 'assertion failed: `{ 1 + 1 } panics`
+///  note: the block completed without panicking'
+/// ```
+/// ```text
+/// thread 'panics::blocks::incorrect_message' panicked at 'assertion failed: `{ panic!("oh no") } message == "oh yes"`
+///   left: "oh no"
+///  right: "oh yes"'
+/// ```
+/// ```text
+/// thread 'panics::blocks::incorrect_payload' panicked at 'assertion failed: `{ panic!("oh no") } payload is i32`
+///  value: "oh no"'
+/// ```
+#[macro_export]
+macro_rules! assert_panics {
+    ($block:expr, message == $expected:expr) => ({
+        let payload = $crate::assert_panics!($block);
+        let message = $crate::panics::message(&*payload).map(::std::string::String::from);
+        match (&message.as_deref(), &$expected) {
+            (message, expected) => {
+                if !matches!(message, Some(message) if *message == *expected) {
+                    $crate::failure::fail("assert_panics", &$crate::report::Report::new(
+                        format_args!("`{} message == {}`", stringify!($block), stringify!($expected)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{}", $crate::panics::Payload(&*payload))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*expected)),
+                        ],
+                    ).source("message ==", &[stringify!($block), stringify!($expected)]))
+                }
+            }
+        }
+        message.unwrap()
+    });
+    ($block:expr, message == $expected:expr, $($arg:tt)+) => ({
+        let payload = $crate::assert_panics!($block, $($arg)+);
+        let message = $crate::panics::message(&*payload).map(::std::string::String::from);
+        match (&message.as_deref(), &$expected) {
+            (message, expected) => {
+                if !matches!(message, Some(message) if *message == *expected) {
+                    $crate::failure::fail("assert_panics", &$crate::report::Report::new(
+                        format_args!("`{} message == {}`", stringify!($block), stringify!($expected)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{}", $crate::panics::Payload(&*payload))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*expected)),
+                        ],
+                    ).source("message ==", &[stringify!($block), stringify!($expected)]).message(format_args!($($arg)+)))
+                }
+            }
+        }
+        message.unwrap()
+    });
+    ($block:expr, message contains $pattern:expr) => ({
+        let payload = $crate::assert_panics!($block);
+        let message = $crate::panics::message(&*payload).map(::std::string::String::from);
+        match (&message.as_deref(), &$pattern) {
+            (message, pattern) => {
+                if !matches!(message, Some(message) if message.contains(*pattern)) {
+                    $crate::failure::fail("assert_panics", &$crate::report::Report::new(
+                        format_args!("`{} message contains {}`", stringify!($block), stringify!($pattern)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{}", $crate::panics::Payload(&*payload))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*pattern)),
+                        ],
+                    ).source("message contains", &[stringify!($block), stringify!($pattern)]))
+                }
+            }
+        }
+        message.unwrap()
+    });
+    ($block:expr, message contains $pattern:expr, $($arg:tt)+) => ({
+        let payload = $crate::assert_panics!($block, $($arg)+);
+        let message = $crate::panics::message(&*payload).map(::std::string::String::from);
+        match (&message.as_deref(), &$pattern) {
+            (message, pattern) => {
+                if !matches!(message, Some(message) if message.contains(*pattern)) {
+                    $crate::failure::fail("assert_panics", &$crate::report::Report::new(
+                        format_args!("`{} message contains {}`", stringify!($block), stringify!($pattern)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{}", $crate::panics::Payload(&*payload))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", &*pattern)),
+                        ],
+                    ).source("message contains", &[stringify!($block), stringify!($pattern)]).message(format_args!($($arg)+)))
+                }
+            }
+        }
+        message.unwrap()
+    });
+    ($block:expr, payload is $type:ty) => ({
+        let payload = $crate::assert_panics!($block);
+        match payload.downcast::<$type>() {
+            Ok(payload) => *payload,
+            Err(payload) => $crate::failure::fail("assert_panics", &$crate::report::Report::new(
+                format_args!("`{} payload is {}`", stringify!($block), stringify!($type)),
+                &[
+                    $crate::report::Field::actual(format_args!("value"), format_args!("{}", $crate::panics::Payload(&*payload))),
+                ],
+            ).source("payload is", &[stringify!($block), stringify!($type)])),
+        }
+    });
+    ($block:expr, payload is $type:ty, $($arg:tt)+) => ({
+        let payload = $crate::assert_panics!($block, $($arg)+);
+        match payload.downcast::<$type>() {
+            Ok(payload) => *payload,
+            Err(payload) => $crate::failure::fail("assert_panics", &$crate::report::Report::new(
+                format_args!("`{} payload is {}`", stringify!($block), stringify!($type)),
+                &[
+                    $crate::report::Field::actual(format_args!("value"), format_args!("{}", $crate::panics::Payload(&*payload))),
+                ],
+            ).source("payload is", &[stringify!($block), stringify!($type)]).message(format_args!($($arg)+))),
+        }
+    });
+    ($block:expr) => ({
+        match $crate::panics::catch(|| $block) {
+            Ok(_) => $crate::failure::fail("assert_panics", &$crate::report::Report::new(
+                format_args!("`{} panics`", stringify!($block)),
+                &[
+                    $crate::report::Field::other(format_args!("note"), format_args!("the block completed without panicking")),
+                ],
+            ).source("panics", &[stringify!($block)])),
+            Err(payload) => payload,
+        }
+    });
+    ($block:expr,) => ({
+        $crate::assert_panics!($block)
+    });
+    ($block:expr, $($arg:tt)+) => ({
+        match $crate::panics::catch(|| $block) {
+            Ok(_) => $crate::failure::fail("assert_panics", &$crate::report::Report::new(
+                format_args!("`{} panics`", stringify!($block)),
+                &[
+                    $crate::report::Field::other(format_args!("note"), format_args!("the block completed without panicking")),
+                ],
+            ).source("panics", &[stringify!($block)]).message(format_args!($($arg)+))),
+            Err(payload) => payload,
+        }
+    });
+}
+
+//=============================================================================================
+// Helpers
+//=============================================================================================
+
+thread_local! {
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+static INSTALL: Once = Once::new();
+
+/// Runs `f`, catching a panic without reporting it through the panic hook.
+///
+/// The first call wraps the current panic hook in one that stays quiet on threads that are
+/// inside `catch`, so panics on other threads, like other tests running in parallel, are still
+/// reported.
+#[doc(hidden)]
+pub fn catch<R, F: FnOnce() -> R>(f: F) -> Result<R, Box<dyn Any + Send>> {
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+    let was_silenced = SILENCED.with(|silenced| silenced.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SILENCED.with(|silenced| silenced.set(was_silenced));
+    result
+}
+
+/// Whether the current thread is inside [catch](fn.catch.html), where panics are expected.
+#[cfg(any(feature = "color", feature = "json", feature = "junit"))]
+pub(crate) fn catching() -> bool {
+    SILENCED.with(Cell::get)
+}
+
+/// The message of a panic payload, if it is a string.
+#[doc(hidden)]
+pub fn message(payload: &(dyn Any + Send)) -> Option<&str> {
+    match payload.downcast_ref::<&'static str>() {
+        Some(message) => Some(message),
+        None => payload.downcast_ref::<String>().map(String::as_str),
+    }
+}
+
+/// Renders a panic payload: its message if it is a string, or a note otherwise.
+#[doc(hidden)]
+pub struct Payload<'a>(pub &'a (dyn Any + Send));
+
+impl std::fmt::Display for Payload<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match message(self.0) {
+            Some(message) => write!(f, "{:?}", message),
+            None => write!(f, "<not a string>"),
+        }
+    }
+}
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod blocks {
+    use std::any::Any;
+
+    #[derive(Debug, PartialEq)]
+    struct Fatal {
+        code: i32,
+    }
+
+    #[test]
+    fn correct() {
+        let v: Vec<u32> = Vec::new();
+        let payload: Box<dyn Any + Send> = assert_panics!({ v[0] });
+        assert!(payload.is::<String>());
+        assert_panics!(panic!("bare expression"));
+    }
+    #[test]
+    fn message() {
+        let message = assert_panics!({ panic!("oh {}", "no") }, message == "oh no");
+        assert_eq!(message, "oh no");
+        assert_panics!({ panic!("static") }, message == "static");
+        assert_panics!({ panic!("static") }, message == String::from("static"));
+    }
+    #[test]
+    fn message_contains() {
+        let v: Vec<u32> = Vec::new();
+        let message = assert_panics!({ v[0] }, message contains "out of bounds");
+        assert!(message.starts_with("index out of bounds"));
+        assert_panics!({ panic!("abc") }, message contains 'b');
+    }
+    #[test]
+    fn payload() {
+        let fatal = assert_panics!({ std::panic::panic_any(Fatal { code: 7 }) }, payload is Fatal);
+        assert_eq!(fatal, Fatal { code: 7 });
+        assert_panics!({ panic!("literal") }, payload is &str);
+    }
+    #[test]
+    fn several_per_test() {
+        for i in 0..3 {
+            assert_panics!({ panic!("{}", i) }, message == i.to_string().as_str());
+        }
+    }
+    #[test]
+    fn mutable_state() {
+        let mut calls = 0;
+        assert_panics!({
+            calls += 1;
+            panic!("after {} call", calls)
+        });
+        assert_eq!(calls, 1);
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_panics!({ panic!() },);
+    }
+    #[test]
+    fn format_string() {
+        assert_panics!({ panic!("x") }, "{} panics", "this");
+        assert_panics!({ panic!("x") }, message == "x", "{} panics", "this");
+        assert_panics!({ panic!("x") }, message contains "x", "{} panics", "this");
+        assert_panics!({ panic!("x") }, payload is &str, "{} panics", "this");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_panics!({ 1 + 1 });
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect_message() {
+        assert_panics!({ panic!("oh no") }, message == "oh yes");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect_contains() {
+        assert_panics!({ panic!("oh no") }, message contains "yes");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect_payload() {
+        assert_panics!({ panic!("oh no") }, payload is i32);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect_non_string_message() {
+        assert_panics!({ std::panic::panic_any(5) }, message == "5");
+    }
+}
+
+#[cfg(test)]
+mod messages {
+    #[test]
+    fn panics() {
+        assert_panics!({ assert_panics!({ 1 + 1 }) }, message == "assertion failed: `{ 1 + 1 } panics`\n note: the block completed without panicking");
+        assert_panics!({ assert_panics!({ 1 + 1 }, "{}", "why") }, message == "assertion failed: `{ 1 + 1 } panics`: why\n note: the block completed without panicking");
+    }
+    #[test]
+    fn message() {
+        assert_panics!({ assert_panics!({ panic!("oh no") }, message == "oh yes") }, message == "assertion failed: `{ panic!(\"oh no\") } message == \"oh yes\"`\n  left: \"oh no\"\n right: \"oh yes\"");
+        assert_panics!({ assert_panics!({ panic!("oh no") }, message contains "yes") }, message == "assertion failed: `{ panic!(\"oh no\") } message contains \"yes\"`\n  left: \"oh no\"\n right: \"yes\"");
+        assert_panics!({ assert_panics!({ std::panic::panic_any(5) }, message == "5") }, message == "assertion failed: `{ std::panic::panic_any(5) } message == \"5\"`\n  left: <not a string>\n right: \"5\"");
+    }
+    #[test]
+    fn payload() {
+        assert_panics!({ assert_panics!({ panic!("oh no") }, payload is i32) }, message == "assertion failed: `{ panic!(\"oh no\") } payload is i32`\n value: \"oh no\"");
+    }
+}
+
+#[cfg(test)]
+mod payloads {
+    use super::{catch, message, Payload};
+
+    #[test]
+    fn messages() {
+        let payload = catch(|| panic!("static")).unwrap_err();
+        assert_eq!(message(&*payload), Some("static"));
+        let payload = catch(|| panic!("{}", "formatted")).unwrap_err();
+        assert_eq!(message(&*payload), Some("formatted"));
+        let payload = catch(|| std::panic::panic_any(1)).unwrap_err();
+        assert_eq!(message(&*payload), None);
+    }
+    #[test]
+    fn rendering() {
+        let payload = catch(|| panic!("quote \" me")).unwrap_err();
+        assert_eq!(Payload(&*payload).to_string(), r#""quote \" me""#);
+        let payload = catch(|| std::panic::panic_any(1)).unwrap_err();
+        assert_eq!(Payload(&*payload).to_string(), "<not a string>");
+    }
+    #[test]
+    fn nested() {
+        let outer = catch(|| {
+            assert!(catch(|| panic!("inner")).is_err());
+            panic!("outer")
+        });
+        assert_eq!(message(&*outer.unwrap_err()), Some("outer"));
+        assert_eq!(catch(|| 5).ok(), Some(5));
+    }
+}
@@ -0,0 +1,618 @@
+#[cfg(feature = "diff")]
+use alloc::{vec, vec::Vec};
+use core::fmt::{self, Write};
+
+//=============================================================================================
+// Reports
+//=============================================================================================
+
+/// What a value in a failure message is, which decides its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// A value the code under test produced.
+    Actual,
+    /// A value the assertion compared it to.
+    Expected,
+    /// Anything else, like a key, a tolerance or a note.
+    Other,
+}
+
+/// A labelled line of a failure message, like `left: 5`.
+#[derive(Debug, Clone, Copy)]
+pub struct Field<'a> {
+    pub label: fmt::Arguments<'a>,
+    pub value: fmt::Arguments<'a>,
+    pub role: Role,
+}
+
+impl<'a> Field<'a> {
+    /// A value the code under test produced.
+    pub fn actual(label: fmt::Arguments<'a>, value: fmt::Arguments<'a>) -> Field<'a> {
+        Field { label, value, role: Role::Actual }
+    }
+
+    /// A value the assertion compared to.
+    pub fn expected(label: fmt::Arguments<'a>, value: fmt::Arguments<'a>) -> Field<'a> {
+        Field { label, value, role: Role::Expected }
+    }
+
+    /// Any other value.
+    pub fn other(label: fmt::Arguments<'a>, value: fmt::Arguments<'a>) -> Field<'a> {
+        Field { label, value, role: Role::Other }
+    }
+}
+
+/// The failure message of an assertion, before it is rendered.
+///
+/// Every totems macro describes its failure with a report, which is rendered as:
+///
+/// ```text
+/// assertion failed: <summary>: <message>
+///   <label>: <value>
+///  <longer label>: <value>
+/// <details>
+/// ```
+///
+/// Labels are aligned on their colons. The message is the optional format string passed to the
+/// macro, and the details are free-form lines like a diff.
+///
+/// The operator and the source of the operands are not rendered, since the summary already
+/// quotes them, but are kept for tools that read failures, like
+/// [json::Record](../json/struct.Record.html).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct Report<'a> {
+    pub summary: fmt::Arguments<'a>,
+    pub fields: &'a [Field<'a>],
+    pub details: Option<fmt::Arguments<'a>>,
+    pub message: Option<fmt::Arguments<'a>>,
+    /// What the assertion checks, like `<`, `contains` or `is Ok(_)`.
+    pub operator: &'a str,
+    /// The source of each operand, in the order they were passed to the macro.
+    pub expressions: &'a [&'a str],
+}
+
+impl<'a> Report<'a> {
+    /// A report with a summary, like ``x < y``, and labelled values.
+    pub fn new(summary: fmt::Arguments<'a>, fields: &'a [Field<'a>]) -> Report<'a> {
+        Report { summary, fields, details: None, message: None, operator: "", expressions: &[] }
+    }
+
+    /// Adds the operator and the source of each operand.
+    pub fn source(self, operator: &'a str, expressions: &'a [&'a str]) -> Report<'a> {
+        Report { operator, expressions, ..self }
+    }
+
+    /// Adds free-form lines after the fields.
+    pub fn details(self, details: fmt::Arguments<'a>) -> Report<'a> {
+        Report { details: Some(details), ..self }
+    }
+
+    /// Adds the message passed to the macro.
+    pub fn message(self, message: fmt::Arguments<'a>) -> Report<'a> {
+        Report { message: Some(message), ..self }
+    }
+
+    /// Renders the report, coloring actual values red and expected values green if `color` is set.
+    ///
+    /// Lines of the details that start with `-` or `+`, like those of a diff, are colored the
+    /// same way.
+    pub fn styled(&self, color: bool) -> Styled<'_> {
+        Styled { report: self, color }
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.styled(false), f)
+    }
+}
+
+/// A [Report](struct.Report.html) rendered with or without color.
+pub struct Styled<'a> {
+    report: &'a Report<'a>,
+    color: bool,
+}
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+fn paint(role: Role) -> Option<&'static str> {
+    match role {
+        Role::Actual => Some(RED),
+        Role::Expected => Some(GREEN),
+        Role::Other => None,
+    }
+}
+
+impl fmt::Display for Styled<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let report = self.report;
+        f.write_str("assertion failed:")?;
+        let mut separator = " ";
+        if report.summary.as_str() != Some("") {
+            write!(f, "{}{}", separator, report.summary)?;
+            separator = ": ";
+        }
+        if let Some(message) = report.message {
+            write!(f, "{}{}", separator, message)?;
+        }
+
+        let width = report.fields.iter().map(|field| width_of(field.label)).max().unwrap_or(0);
+        for field in report.fields {
+            let pad = width - width_of(field.label);
+            write!(f, "\n {:pad$}{}: ", "", field.label, pad = pad)?;
+            let color = paint(field.role).filter(|_| self.color);
+            if let Some(color) = color {
+                f.write_str(color)?;
+            }
+            Indented { f: &mut *f, indent: width + 3 }.write_fmt(field.value)?;
+            if color.is_some() {
+                f.write_str(RESET)?;
+            }
+        }
+
+        if let Some(details) = report.details {
+            let mut lines = Lines { f, pending: 1, start: true, color: self.color, open: false };
+            lines.write_fmt(details)?;
+            lines.close()?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts the characters of `args` without allocating.
+fn width_of(args: fmt::Arguments<'_>) -> usize {
+    struct Count(usize);
+
+    impl Write for Count {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+
+    let mut count = Count(0);
+    let _ = count.write_fmt(args);
+    count.0
+}
+
+/// Indents every line of a value after the first, so that values spanning several lines, like
+/// those rendered with `{:#?}`, stay under their first line.
+struct Indented<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    indent: usize,
+}
+
+impl Write for Indented<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                write!(self.f, "\n{:indent$}", "", indent = self.indent)?;
+            }
+            self.f.write_str(line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the details of a report line by line, holding back newlines until more text follows,
+/// so that the message never ends with a newline, and coloring diff lines.
+struct Lines<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    pending: usize,
+    start: bool,
+    color: bool,
+    open: bool,
+}
+
+impl Lines<'_, '_> {
+    fn close(&mut self) -> fmt::Result {
+        if self.open {
+            self.open = false;
+            self.f.write_str(RESET)?;
+        }
+        Ok(())
+    }
+}
+
+impl Write for Lines<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.close()?;
+                self.pending += 1;
+                self.start = true;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            for _ in 0..self.pending {
+                self.f.write_str("\n")?;
+            }
+            self.pending = 0;
+            if self.start && self.color {
+                let role = match line.as_bytes()[0] {
+                    b'-' => Role::Actual,
+                    b'+' => Role::Expected,
+                    _ => Role::Other,
+                };
+                if let Some(color) = paint(role) {
+                    self.f.write_str(color)?;
+                    self.open = true;
+                }
+            }
+            self.start = false;
+            self.f.write_str(line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders a compared value with `{:#?}` when the `diff` feature is enabled, and with `{:?}`
+/// otherwise.
+#[doc(hidden)]
+pub struct Pretty<'a, T: ?Sized>(pub &'a T);
+
+impl<T: fmt::Debug + ?Sized> fmt::Debug for Pretty<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match cfg!(feature = "diff") {
+            true => write!(f, "{:#?}", self.0),
+            false => write!(f, "{:?}", self.0),
+        }
+    }
+}
+
+/// Renders a line diff between an actual and an expected value, as the details of a report.
+///
+/// With the `diff` feature, the values are rendered with `{:#?}`, and the diff is drawn when
+/// either spans several lines: removed lines, prefixed with `-`, are only in the actual value,
+/// and added lines, prefixed with `+`, are only in the expected value. Without the feature, or
+/// when both values fit on one line, nothing is rendered.
+#[doc(hidden)]
+pub struct Diff<'a, A: ?Sized, E: ?Sized>(pub &'a A, pub &'a E);
+
+impl<A: fmt::Debug + ?Sized, E: fmt::Debug + ?Sized> fmt::Display for Diff<'_, A, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "diff")]
+        {
+            let (actual, expected) = (alloc::format!("{:#?}", self.0), alloc::format!("{:#?}", self.1));
+            if actual != expected && (actual.contains('\n') || expected.contains('\n')) {
+                f.write_str(" diff (-actual +expected):")?;
+                let (actual, expected): (Vec<&str>, Vec<&str>) = (actual.lines().collect(), expected.lines().collect());
+                for (sign, line) in line_diff(&actual, &expected) {
+                    write!(f, "\n{}{}", sign, line)?;
+                }
+            }
+        }
+        #[cfg(not(feature = "diff"))]
+        let _ = f;
+        Ok(())
+    }
+}
+
+/// Pairs every line of `actual` and `expected` with `-`, `+` or ` `, keeping the longest common
+/// subsequence of lines in place.
+///
+/// Inputs too large for the quadratic table are diffed as entirely removed and added.
+#[cfg(feature = "diff")]
+fn line_diff<'a>(actual: &[&'a str], expected: &[&'a str]) -> Vec<(char, &'a str)> {
+    const MAX_CELLS: usize = 1 << 22;
+
+    let (n, m) = (actual.len(), expected.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_CELLS {
+        let removed = actual.iter().map(|&line| ('-', line));
+        return removed.chain(expected.iter().map(|&line| ('+', line))).collect();
+    }
+    // lengths[i][j] is the length of the longest common subsequence of actual[i..] and expected[j..].
+    let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+    let at = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[at(i, j)] = match actual[i] == expected[j] {
+                true => lengths[at(i + 1, j + 1)] + 1,
+                false => lengths[at(i + 1, j)].max(lengths[at(i, j + 1)]),
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::with_capacity(n + m);
+    while i < n || j < m {
+        if i < n && j < m && actual[i] == expected[j] {
+            lines.push((' ', actual[i]));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lengths[at(i + 1, j)] >= lengths[at(i, j + 1)]) {
+            lines.push(('-', actual[i]));
+            i += 1;
+        } else {
+            lines.push(('+', expected[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+/// Whether failures written to standard error are colored.
+///
+/// With the `color` feature, they are colored when standard error is a terminal and the
+/// [`NO_COLOR`](https://no-color.org) environment variable is not set. Without it, they never
+/// are. Panic messages themselves are always plain.
+pub fn color() -> bool {
+    #[cfg(feature = "color")]
+    {
+        use std::io::IsTerminal;
+
+        let no_color = match std::env::var_os("NO_COLOR") {
+            Some(value) => !value.is_empty(),
+            None => false,
+        };
+        !no_color && std::io::stderr().is_terminal()
+    }
+    #[cfg(not(feature = "color"))]
+    {
+        false
+    }
+}
+
+//=============================================================================================
+// Rendering
+//=============================================================================================
+
+/// Renders a value in failure messages with `Display` instead of `Debug`.
+///
+/// Wrap both operands of a comparison, since the wrappers compare by the values they hold.
+///
+/// ### Example
+///
+/// ```
+/// use totems::{assert_le, assert_ok};
+/// use totems::report::Displayed;
+/// use std::net::Ipv4Addr;
+///
+/// let result = "127.0.0.1".parse::<Ipv4Addr>();
+/// assert_ok!(&result.as_ref().map(Displayed), value == &Displayed(&Ipv4Addr::LOCALHOST));
+/// assert_le!(Displayed(Ipv4Addr::LOCALHOST), Displayed(Ipv4Addr::BROADCAST));
+/// ```
+#[derive(Clone, Copy, Default, Hash)]
+pub struct Displayed<T>(pub T);
+
+impl<T: fmt::Display> fmt::Debug for Displayed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Renders a value in failure messages with a closure, like `|v, f| write!(f, "{}", v.len())`.
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_lt;
+/// use totems::report::RenderedWith;
+///
+/// let summary = |v: &Vec<u8>, f: &mut std::fmt::Formatter| write!(f, "{} bytes", v.len());
+/// assert_lt!(RenderedWith(vec![1; 4], summary), RenderedWith(vec![2; 4096], summary));
+/// ```
+#[derive(Clone, Copy)]
+pub struct RenderedWith<T, F>(pub T, pub F);
+
+impl<T, F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result> fmt::Debug for RenderedWith<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(&self.0, f)
+    }
+}
+
+/// Renders a value in failure messages as its type name, for types with no formatting trait.
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_some;
+/// use totems::report::Opaque;
+///
+/// #[derive(PartialEq)]
+/// struct Token(u64);
+///
+/// let token = Some(Token(7));
+/// assert_some!(&token.as_ref().map(Opaque), value == &Opaque(&Token(7)));
+/// ```
+#[derive(Clone, Copy, Default, Hash)]
+pub struct Opaque<T>(pub T);
+
+impl<T> fmt::Debug for Opaque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(core::any::type_name::<T>())
+    }
+}
+
+/// Forwards comparisons and `Deref` of a rendering wrapper to the value it holds.
+macro_rules! forward {
+    ($wrapper:ident $(, $rest:ident)*) => {
+        impl<T: PartialEq<U>, U $(, $rest)*> PartialEq<$wrapper<U $(, $rest)*>> for $wrapper<T $(, $rest)*> {
+            fn eq(&self, other: &$wrapper<U $(, $rest)*>) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<T: Eq $(, $rest)*> Eq for $wrapper<T $(, $rest)*> {}
+
+        impl<T: PartialOrd<U>, U $(, $rest)*> PartialOrd<$wrapper<U $(, $rest)*>> for $wrapper<T $(, $rest)*> {
+            fn partial_cmp(&self, other: &$wrapper<U $(, $rest)*>) -> Option<core::cmp::Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+
+        impl<T: Ord $(, $rest)*> Ord for $wrapper<T $(, $rest)*> {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl<T $(, $rest)*> core::ops::Deref for $wrapper<T $(, $rest)*> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+    };
+}
+
+forward!(Displayed);
+forward!(Opaque);
+forward!(RenderedWith, F);
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod rendering {
+    use super::{Field, Report};
+
+    #[test]
+    fn aligned() {
+        let rendered = Report::new(
+            format_args!("`x < y`"),
+            &[
+                Field::actual(format_args!("left"), format_args!("{}", 5)),
+                Field::expected(format_args!("right"), format_args!("{}", 4)),
+                Field::other(format_args!("{}", "ordering"), format_args!("Greater")),
+            ],
+        )
+        .to_string();
+        assert_eq!(
+            rendered,
+            "assertion failed: `x < y`\n     left: 5\n    right: 4\n ordering: Greater",
+        );
+    }
+    #[test]
+    fn message() {
+        let rendered = Report::new(format_args!("`(a)`"), &[]).message(format_args!("{} failed", "it")).to_string();
+        assert_eq!(rendered, "assertion failed: `(a)`: it failed");
+        let report = Report::new(format_args!(""), &[]).message(format_args!("no summary"));
+        assert_eq!(report.to_string(), "assertion failed: no summary");
+        assert_eq!(Report::new(format_args!(""), &[]).to_string(), "assertion failed:");
+    }
+    #[test]
+    fn details() {
+        let fields = [Field::other(format_args!("key"), format_args!("1"))];
+        let report = Report::new(format_args!("(x)"), &fields).details(format_args!("-[1]: 2\n+[1]: 3\n\n"));
+        assert_eq!(report.to_string(), "assertion failed: (x)\n key: 1\n-[1]: 2\n+[1]: 3");
+        let rendered = Report::new(format_args!("(x)"), &fields).details(format_args!("{}", "")).to_string();
+        assert_eq!(rendered, "assertion failed: (x)\n key: 1");
+    }
+    #[test]
+    fn multi_line_values() {
+        let rendered = Report::new(
+            format_args!("(x)"),
+            &[
+                Field::actual(format_args!("left"), format_args!("{:#?}", (1, 2))),
+                Field::expected(format_args!("right"), format_args!("3")),
+            ],
+        )
+        .to_string();
+        assert_eq!(rendered, "assertion failed: (x)\n  left: (\n            1,\n            2,\n        )\n right: 3");
+    }
+    #[test]
+    fn colored() {
+        let fields = [
+            Field::actual(format_args!("left"), format_args!("1")),
+            Field::expected(format_args!("right"), format_args!("2")),
+            Field::other(format_args!("note"), format_args!("3")),
+        ];
+        let report = Report::new(format_args!("(x)"), &fields).details(format_args!("-[0]: 1\n+[0]: 2\n same\n"));
+        assert_eq!(
+            report.styled(true).to_string(),
+            "assertion failed: (x)\n  left: \x1b[31m1\x1b[0m\n right: \x1b[32m2\x1b[0m\n  note: 3\n\x1b[31m-[0]: 1\x1b[0m\n\x1b[32m+[0]: 2\x1b[0m\n same",
+        );
+    }
+}
+
+#[cfg(test)]
+mod diffs {
+    use super::{Diff, Pretty};
+
+    #[test]
+    #[cfg(not(feature = "diff"))]
+    fn disabled() {
+        assert_eq!(format!("{:?}", Pretty(&(1, 2))), "(1, 2)");
+        assert_eq!(Diff(&(1, 2), &(1, 3)).to_string(), "");
+    }
+    #[test]
+    #[cfg(feature = "diff")]
+    fn multi_line() {
+        assert_eq!(format!("{:?}", Pretty(&(1, 2))), "(\n    1,\n    2,\n)");
+        assert_eq!(Diff(&(1, 2), &(1, 3)).to_string(), " diff (-actual +expected):\n (\n     1,\n-    2,\n+    3,\n )");
+    }
+    #[test]
+    #[cfg(feature = "diff")]
+    fn single_line() {
+        assert_eq!(Diff(&1, &2).to_string(), "");
+        assert_eq!(Diff(&(1, 2), &(1, 2)).to_string(), "");
+    }
+    #[test]
+    #[cfg(feature = "diff")]
+    fn lines() {
+        use super::line_diff;
+        assert_eq!(
+            line_diff(&["a", "b", "c", "d"], &["a", "c", "e", "d"]),
+            vec![(' ', "a"), ('-', "b"), (' ', "c"), ('+', "e"), (' ', "d")],
+        );
+        assert_eq!(line_diff(&[], &["a"]), vec![('+', "a")]);
+        assert_eq!(line_diff(&["a"], &[]), vec![('-', "a")]);
+    }
+}
+
+#[cfg(test)]
+mod wrappers {
+    use super::{Displayed, Opaque, RenderedWith};
+    use crate::{assert_le, assert_lt, assert_ok, assert_panics, assert_some};
+    use std::fmt;
+
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    struct Token(u64);
+
+    impl fmt::Display for Token {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "token #{}", self.0)
+        }
+    }
+
+    #[test]
+    fn displayed() {
+        assert_le!(Displayed(Token(1)), Displayed(Token(1)));
+        assert_panics!(
+            { assert_le!(Displayed(Token(2)), Displayed(Token(1))) },
+            message == "assertion failed: `Displayed(Token(2)) <= Displayed(Token(1))`\n  left: token #2\n right: token #1"
+        );
+    }
+    #[test]
+    fn rendered_with() {
+        let length = |v: &Vec<u8>, f: &mut fmt::Formatter<'_>| write!(f, "{} bytes", v.len());
+        assert_lt!(RenderedWith(vec![1], length), RenderedWith(vec![2, 0], length));
+        assert_panics!(
+            { assert_lt!(RenderedWith(vec![3; 3], length), RenderedWith(vec![2; 8], length)) },
+            message contains "\n  left: 3 bytes\n right: 8 bytes"
+        );
+    }
+    #[test]
+    fn opaque() {
+        let token = Some(Token(7));
+        assert_some!(&token.as_ref().map(Opaque), value == &Opaque(&Token(7)));
+        assert_panics!(
+            { assert_some!(&token.as_ref().map(Opaque), value == &Opaque(&Token(8))) },
+            message contains "\n  left: &totems::report::wrappers::Token\n right: &totems::report::wrappers::Token"
+        );
+    }
+    #[test]
+    fn inner_values() {
+        let result: Result<Token, u32> = Ok(Token(1));
+        assert_panics!(
+            { assert_ok!(&result.as_ref().map(Displayed), value == &Displayed(&Token(2))) },
+            message contains "\n  left: token #1\n right: token #2"
+        );
+    }
+}
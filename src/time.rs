@@ -0,0 +1,733 @@
+use crate::failure;
+use crate::report::{Field, Report};
+use std::fmt;
+use std::time::{Duration, Instant, SystemTime};
+
+//=============================================================================================
+// Macros
+//=============================================================================================
+
+/// Asserts that a [Duration](https://doc.rust-lang.org/std/time/struct.Duration.html) is within a
+/// `tolerance` of the expected duration.
+///
+/// ### Parameters
+///
+/// - `actual` The measured duration.
+/// - `expected` The expected duration.
+/// - `tolerance` How far apart the durations may be, either as a literal with a unit (`ns`,
+///   `us`, `ms` or `s`), like `10ms` or `1.5s`, or as a `Duration`. A literal with any other
+///   unit, or without one, is a compile error.
+///
+/// ### Dependencies
+///
+/// - `actual`, `expected` and `tolerance` must be `Duration`s.
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_duration_near;
+/// use std::time::Duration;
+/// let elapsed = Duration::from_micros(104_200);
+/// assert_duration_near!(elapsed, Duration::from_millis(100), tolerance = 10ms);
+/// assert_duration_near!(elapsed, Duration::from_millis(100), tolerance = Duration::from_millis(5));
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'time::duration_near::incorrect' panicked at 'assertion failed: `elapsed ≈ Duration::from_millis(100)`
+///        left: 114.2ms
+///       right: 100ms
+///  difference: +14.2ms
+///   tolerance: 10ms'
+/// ```
+#[macro_export]
+macro_rules! assert_duration_near {
+    ($actual:expr, $expected:expr, tolerance = $tolerance:literal) => ({
+        const TOLERANCE: ::core::time::Duration = $crate::time::tolerance(stringify!($tolerance));
+        $crate::assert_duration_near!($actual, $expected, tolerance = TOLERANCE)
+    });
+    ($actual:expr, $expected:expr, tolerance = $tolerance:literal, $($arg:tt)+) => ({
+        const TOLERANCE: ::core::time::Duration = $crate::time::tolerance(stringify!($tolerance));
+        $crate::assert_duration_near!($actual, $expected, tolerance = TOLERANCE, $($arg)+)
+    });
+    ($actual:expr, $expected:expr, tolerance = $tolerance:expr) => ({
+        match (&$actual, &$expected, &$tolerance) {
+            (actual, expected, tolerance) => {
+                if let Some(difference) = $crate::time::outside(*actual, *expected, *tolerance) {
+                    $crate::failure::fail("assert_duration_near", &$crate::report::Report::new(
+                        format_args!("`{} ≈ {}`", stringify!($actual), stringify!($expected)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{}", $crate::time::Human(*actual))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{}", $crate::time::Human(*expected))),
+                            $crate::report::Field::other(format_args!("difference"), format_args!("{}", difference)),
+                            $crate::report::Field::other(format_args!("tolerance"), format_args!("{}", $crate::time::Human(*tolerance))),
+                        ],
+                    ).source("≈", &[stringify!($actual), stringify!($expected)]))
+                }
+            }
+        }
+    });
+    ($actual:expr, $expected:expr, tolerance = $tolerance:expr,) => ({
+        $crate::assert_duration_near!($actual, $expected, tolerance = $tolerance)
+    });
+    ($actual:expr, $expected:expr, tolerance = $tolerance:expr, $($arg:tt)+) => ({
+        match (&$actual, &$expected, &$tolerance) {
+            (actual, expected, tolerance) => {
+                if let Some(difference) = $crate::time::outside(*actual, *expected, *tolerance) {
+                    $crate::failure::fail("assert_duration_near", &$crate::report::Report::new(
+                        format_args!("`{} ≈ {}`", stringify!($actual), stringify!($expected)),
+                        &[
+                            $crate::report::Field::actual(format_args!("left"), format_args!("{}", $crate::time::Human(*actual))),
+                            $crate::report::Field::expected(format_args!("right"), format_args!("{}", $crate::time::Human(*expected))),
+                            $crate::report::Field::other(format_args!("difference"), format_args!("{}", difference)),
+                            $crate::report::Field::other(format_args!("tolerance"), format_args!("{}", $crate::time::Human(*tolerance))),
+                        ],
+                    ).source("≈", &[stringify!($actual), stringify!($expected)]).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that one point in time comes strictly before another.
+///
+/// ### Parameters
+///
+/// - `first` The point in time that should come first.
+/// - `second` The point in time that should come second.
+///
+/// ### Dependencies
+///
+/// - `first` and `second` must both be an [Instant](https://doc.rust-lang.org/std/time/struct.Instant.html),
+///   or both be a [SystemTime](https://doc.rust-lang.org/std/time/struct.SystemTime.html).
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_before;
+/// use std::time::{Duration, Instant};
+/// let start = Instant::now();
+/// let end = start + Duration::from_millis(5);
+/// assert_before!(start, end);
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'time::before::incorrect' panicked at 'assertion failed: `end before start`
+///  end is 1.5ms after start'
+/// ```
+#[macro_export]
+macro_rules! assert_before {
+    ($first:expr, $second:expr) => ({
+        match (&$first, &$second) {
+            (first, second) => {
+                if let Some(lateness) = $crate::time::lateness(*first, *second) {
+                    $crate::failure::fail("assert_before", &$crate::report::Report::new(
+                        format_args!("`{} before {}`", stringify!($first), stringify!($second)),
+                        &[],
+                    ).source("before", &[stringify!($first), stringify!($second)]).details(format_args!(" {} is {} {}", stringify!($first), lateness, stringify!($second))))
+                }
+            }
+        }
+    });
+    ($first:expr, $second:expr,) => ({
+        $crate::assert_before!($first, $second)
+    });
+    ($first:expr, $second:expr, $($arg:tt)+) => ({
+        match (&$first, &$second) {
+            (first, second) => {
+                if let Some(lateness) = $crate::time::lateness(*first, *second) {
+                    $crate::failure::fail("assert_before", &$crate::report::Report::new(
+                        format_args!("`{} before {}`", stringify!($first), stringify!($second)),
+                        &[],
+                    ).source("before", &[stringify!($first), stringify!($second)]).details(format_args!(" {} is {} {}", stringify!($first), lateness, stringify!($second))).message(format_args!($($arg)+)))
+                }
+            }
+        }
+    });
+}
+
+/// Asserts that a block of code completes within a time budget, and returns its value.
+///
+/// With `iterations = n`, the block runs `n` times and the median time is compared to the
+/// budget instead, filtering out noise like a context switch during a single run. The value of
+/// the last run is returned.
+///
+/// ### Parameters
+///
+/// - `budget` The longest the block may take, as a [Duration](https://doc.rust-lang.org/std/time/struct.Duration.html).
+/// - `iterations` ***(optional)*** How many times to run the block.
+/// - `block` The code to time.
+///
+/// ### Example
+///
+/// ```
+/// use totems::assert_completes_within;
+/// use std::time::Duration;
+/// let sum = assert_completes_within!(Duration::from_secs(1), { (1..=100).sum::<u32>() });
+/// assert_eq!(sum, 5050);
+/// let sum = assert_completes_within!(Duration::from_secs(1), iterations = 5, { (1..=100).sum::<u32>() });
+/// assert_eq!(sum, 5050);
+/// ```
+///
+/// ### Example Error Messages
+///
+/// ```text
+/// thread 'time::completes_within::incorrect' panicked at 'assertion failed: `{ thread::sleep(Duration::from_millis(20)) } <= Duration::from_millis(1)`
+///   left: 20.08ms
+///  right: 1ms'
+/// ```
+/// ```text
+/// thread 'time::completes_within::incorrect_median' panicked at 'assertion failed: `{ thread::sleep(Duration::from_millis(5)) } <= Duration::from_millis(1)`
+///     left: 5.071ms
+///    right: 1ms
+///  samples: [5.066ms, 5.071ms, 5.08ms]
+///     note: left is the median of 3 runs'
+/// ```
+#[macro_export]
+macro_rules! assert_completes_within {
+    ($budget:expr, iterations = $iterations:expr, $block:expr) => ({
+        let iterations: usize = $iterations;
+        assert!(iterations > 0, "assert_completes_within! needs at least one iteration");
+        let mut samples = ::std::vec::Vec::with_capacity(iterations);
+        let mut value = ::std::option::Option::None;
+        for _ in 0..iterations {
+            let start = ::std::time::Instant::now();
+            let result = $block;
+            samples.push(start.elapsed());
+            value = ::std::option::Option::Some(result);
+        }
+        if let Some(over) = $crate::time::over_budget(&mut samples, $budget) {
+            over.fail(&[stringify!($block), stringify!($budget)], None)
+        }
+        value.unwrap()
+    });
+    ($budget:expr, iterations = $iterations:expr, $block:expr,) => ({
+        $crate::assert_completes_within!($budget, iterations = $iterations, $block)
+    });
+    ($budget:expr, iterations = $iterations:expr, $block:expr, $($arg:tt)+) => ({
+        let iterations: usize = $iterations;
+        assert!(iterations > 0, "assert_completes_within! needs at least one iteration");
+        let mut samples = ::std::vec::Vec::with_capacity(iterations);
+        let mut value = ::std::option::Option::None;
+        for _ in 0..iterations {
+            let start = ::std::time::Instant::now();
+            let result = $block;
+            samples.push(start.elapsed());
+            value = ::std::option::Option::Some(result);
+        }
+        if let Some(over) = $crate::time::over_budget(&mut samples, $budget) {
+            over.fail(&[stringify!($block), stringify!($budget)], Some(format_args!($($arg)+)))
+        }
+        value.unwrap()
+    });
+    ($budget:expr, $block:expr) => ({
+        let start = ::std::time::Instant::now();
+        let value = $block;
+        let mut samples = [start.elapsed()];
+        if let Some(over) = $crate::time::over_budget(&mut samples, $budget) {
+            over.fail(&[stringify!($block), stringify!($budget)], None)
+        }
+        value
+    });
+    ($budget:expr, $block:expr,) => ({
+        $crate::assert_completes_within!($budget, $block)
+    });
+    ($budget:expr, $block:expr, $($arg:tt)+) => ({
+        let start = ::std::time::Instant::now();
+        let value = $block;
+        let mut samples = [start.elapsed()];
+        if let Some(over) = $crate::time::over_budget(&mut samples, $budget) {
+            over.fail(&[stringify!($block), stringify!($budget)], Some(format_args!($($arg)+)))
+        }
+        value
+    });
+}
+
+//=============================================================================================
+// Helpers
+//=============================================================================================
+
+/// Renders a duration in the largest unit that keeps it at or above one, like `14.2ms`.
+#[doc(hidden)]
+pub struct Human(pub Duration);
+
+impl fmt::Display for Human {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        let (scale, unit) = match nanos {
+            0..=999 => (1, "ns"),
+            1_000..=999_999 => (1_000, "µs"),
+            1_000_000..=999_999_999 => (1_000_000, "ms"),
+            _ => (1_000_000_000, "s"),
+        };
+        let whole = nanos / scale;
+        // Three decimals at most, without trailing zeros.
+        let fraction = (nanos % scale) * 1_000 / scale;
+        match fraction {
+            0 => write!(f, "{}{}", whole, unit),
+            _ => {
+                let fraction = format!("{:03}", fraction);
+                write!(f, "{}.{}{}", whole, fraction.trim_end_matches('0'), unit)
+            }
+        }
+    }
+}
+
+/// Parses a tolerance literal of [assert_duration_near](../macro.assert_duration_near.html),
+/// like `10ms` or `1.5s`, rounding it to the nearest nanosecond.
+///
+/// The macro evaluates it in a constant, so a literal without a valid unit, or one too large
+/// for a `Duration` of `u64` nanoseconds, fails to compile:
+///
+/// ```compile_fail
+/// use totems::assert_duration_near;
+/// use std::time::Duration;
+/// assert_duration_near!(Duration::from_secs(60), Duration::from_secs(60), tolerance = 10min);
+/// ```
+///
+/// ```compile_fail
+/// use totems::assert_duration_near;
+/// use std::time::Duration;
+/// assert_duration_near!(Duration::from_secs(60), Duration::from_secs(60), tolerance = 5);
+/// ```
+///
+/// ```compile_fail
+/// use totems::assert_duration_near;
+/// use std::time::Duration;
+/// assert_duration_near!(Duration::from_secs(60), Duration::from_secs(60), tolerance = 20000000000s);
+/// ```
+#[doc(hidden)]
+pub const fn tolerance(literal: &str) -> Duration {
+    const INVALID: &str = "invalid tolerance: expected a number followed by ns, us, ms or s";
+    const TOO_LARGE: &str = "invalid tolerance: at most 18446744073.709551615s fits in a Duration of u64 nanoseconds";
+
+    let bytes = literal.as_bytes();
+    let mut split = 0;
+    while split < bytes.len() && !bytes[split].is_ascii_alphabetic() {
+        split += 1;
+    }
+    let (amount, unit) = bytes.split_at(split);
+    let nanos_per_unit: u64 = match unit {
+        [b'n', b's'] => 1,
+        [b'u', b's'] => 1_000,
+        [b'm', b's'] => 1_000_000,
+        [b's'] => 1_000_000_000,
+        _ => panic!("{}", INVALID),
+    };
+    if amount.is_empty() || amount[0] == b'.' {
+        panic!("{}", INVALID);
+    }
+
+    // The whole part, then each fractional digit scaled by the nanoseconds its place is worth.
+    let mut whole: u64 = 0;
+    let mut i = 0;
+    while i < amount.len() && amount[i] != b'.' {
+        match amount[i] {
+            b'0'..=b'9' => match whole.checked_mul(10) {
+                Some(tens) => whole = tens + (amount[i] - b'0') as u64,
+                None => panic!("{}", TOO_LARGE),
+            },
+            b'_' => {}
+            _ => panic!("{}", INVALID),
+        }
+        i += 1;
+    }
+    let mut nanos = match whole.checked_mul(nanos_per_unit) {
+        Some(nanos) => nanos,
+        None => panic!("{}", TOO_LARGE),
+    };
+    let mut place = nanos_per_unit;
+    i += 1;
+    while i < amount.len() {
+        let digit = match amount[i] {
+            b'0'..=b'9' => (amount[i] - b'0') as u64,
+            b'_' => {
+                i += 1;
+                continue;
+            }
+            _ => panic!("{}", INVALID),
+        };
+        let add = match place {
+            0 => 0,
+            1 => {
+                place = 0;
+                (digit >= 5) as u64
+            }
+            _ => {
+                place /= 10;
+                digit * place
+            }
+        };
+        nanos = match nanos.checked_add(add) {
+            Some(nanos) => nanos,
+            None => panic!("{}", TOO_LARGE),
+        };
+        i += 1;
+    }
+    Duration::from_nanos(nanos)
+}
+
+/// Compares two durations within a `tolerance`.
+///
+/// Returns `None` when they are near enough, or the signed difference from `expected`.
+#[doc(hidden)]
+pub fn outside(actual: Duration, expected: Duration, tolerance: Duration) -> Option<String> {
+    let (sign, difference) = match actual >= expected {
+        true => ('+', actual - expected),
+        false => ('-', expected - actual),
+    };
+    match difference <= tolerance {
+        true => None,
+        false => Some(format!("{}{}", sign, Human(difference))),
+    }
+}
+
+/// Compares the median of the timed `samples` to a `budget`, sorting the samples.
+///
+/// Returns `None` when the median is within budget.
+#[doc(hidden)]
+pub fn over_budget(samples: &mut [Duration], budget: Duration) -> Option<OverBudget<'_>> {
+    samples.sort();
+    let middle = samples.len() / 2;
+    let median = match samples.len() % 2 {
+        0 => (samples[middle - 1] + samples[middle]) / 2,
+        _ => samples[middle],
+    };
+    match median <= budget {
+        true => None,
+        false => Some(OverBudget { samples, budget, median }),
+    }
+}
+
+/// The timed samples of [assert_completes_within](../macro.assert_completes_within.html) whose
+/// median exceeded the budget.
+#[doc(hidden)]
+#[derive(Debug, PartialEq)]
+pub struct OverBudget<'a> {
+    samples: &'a [Duration],
+    budget: Duration,
+    median: Duration,
+}
+
+impl OverBudget<'_> {
+    /// Fails the assertion, reporting a single sample as the elapsed time.
+    ///
+    /// `expressions` is the source of the block and of the budget.
+    #[track_caller]
+    pub fn fail(&self, expressions: &[&str], message: Option<fmt::Arguments<'_>>) -> ! {
+        let (block, budget) = (expressions[0], expressions[1]);
+        match self.samples {
+            [elapsed] => finish(Report::new(
+                format_args!("`{} <= {}`", block, budget),
+                &[
+                    Field::actual(format_args!("left"), format_args!("{}", Human(*elapsed))),
+                    Field::expected(format_args!("right"), format_args!("{}", Human(self.budget))),
+                ],
+            ).source("<=", expressions), message),
+            samples => finish(Report::new(
+                format_args!("`{} <= {}`", block, budget),
+                &[
+                    Field::actual(format_args!("left"), format_args!("{}", Human(self.median))),
+                    Field::expected(format_args!("right"), format_args!("{}", Human(self.budget))),
+                    Field::other(format_args!("samples"), format_args!("{}", Samples(samples))),
+                    Field::other(format_args!("note"), format_args!("left is the median of {} runs", samples.len())),
+                ],
+            ).source("<=", expressions), message),
+        }
+    }
+}
+
+#[track_caller]
+fn finish(report: Report<'_>, message: Option<fmt::Arguments<'_>>) -> ! {
+    match message {
+        Some(message) => failure::fail("assert_completes_within", &report.message(message)),
+        None => failure::fail("assert_completes_within", &report),
+    }
+}
+
+/// Renders durations as a list, e.g. `[1ms, 2.5ms]`.
+struct Samples<'a>(&'a [Duration]);
+
+impl fmt::Display for Samples<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, &sample) in self.0.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{}{}", separator, Human(sample))?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// A point in time that [assert_before](../macro.assert_before.html) can order.
+#[doc(hidden)]
+pub trait Timestamp: Copy + Ord {
+    /// The time elapsed from `earlier` to `self`, if `earlier` is not later than `self`.
+    fn since(self, earlier: Self) -> Option<Duration>;
+}
+
+impl Timestamp for Instant {
+    fn since(self, earlier: Self) -> Option<Duration> {
+        self.checked_duration_since(earlier)
+    }
+}
+
+impl Timestamp for SystemTime {
+    fn since(self, earlier: Self) -> Option<Duration> {
+        self.duration_since(earlier).ok()
+    }
+}
+
+/// Checks that `first` comes before `second`.
+///
+/// Returns `None` when it does, or how late `first` is relative to `second`.
+#[doc(hidden)]
+pub fn lateness<T: Timestamp>(first: T, second: T) -> Option<String> {
+    if first < second {
+        return None;
+    }
+    match first.since(second) {
+        Some(late) if late > Duration::from_nanos(0) => Some(format!("{} after", Human(late))),
+        _ => Some(String::from("at the same time as")),
+    }
+}
+
+//=============================================================================================
+// Unit Tests
+//=============================================================================================
+
+#[cfg(test)]
+mod duration_near {
+    use std::time::Duration;
+
+    #[test]
+    fn correct() {
+        let elapsed = Duration::from_micros(104_200);
+        assert_duration_near!(elapsed, Duration::from_millis(100), tolerance = 10ms);
+        assert_duration_near!(elapsed, Duration::from_millis(110), tolerance = 5.8ms);
+        assert_duration_near!(elapsed, Duration::from_millis(100), tolerance = 4200us);
+        assert_duration_near!(elapsed, Duration::from_secs(0), tolerance = 0.2s);
+        assert_duration_near!(elapsed, elapsed, tolerance = 0ns);
+        assert_duration_near!(elapsed, Duration::from_millis(100), tolerance = Duration::from_millis(5));
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_duration_near!(Duration::from_millis(1), Duration::from_millis(2), tolerance = Duration::from_millis(1),);
+    }
+    #[test]
+    fn format_string() {
+        let elapsed = Duration::from_millis(99);
+        assert_duration_near!(elapsed, Duration::from_millis(100), tolerance = 1ms, "took {:?}", elapsed);
+        assert_duration_near!(elapsed, Duration::from_millis(100), tolerance = Duration::from_millis(1), "took {:?}", elapsed);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_duration_near!(Duration::from_micros(114_200), Duration::from_millis(100), tolerance = 10ms);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect_below() {
+        assert_duration_near!(Duration::from_millis(89), Duration::from_millis(100), tolerance = Duration::from_millis(10));
+    }
+}
+
+#[cfg(test)]
+mod before {
+    use std::time::{Duration, Instant, SystemTime};
+
+    #[test]
+    fn correct() {
+        let start = Instant::now();
+        let end = start + Duration::from_millis(5);
+        assert_before!(start, end);
+        assert_before!(SystemTime::UNIX_EPOCH, SystemTime::now());
+    }
+    #[test]
+    fn trailing_comma() {
+        let start = Instant::now();
+        assert_before!(start, start + Duration::from_nanos(1),);
+    }
+    #[test]
+    fn format_string() {
+        let start = Instant::now();
+        assert_before!(start, start + Duration::from_secs(1), "{} comes first", "start");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        let start = Instant::now();
+        let end = start + Duration::from_micros(1_500);
+        assert_before!(end, start);
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect_same() {
+        let now = SystemTime::now();
+        assert_before!(now, now);
+    }
+}
+
+#[cfg(test)]
+mod completes_within {
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn correct() {
+        let sum = assert_completes_within!(Duration::from_secs(5), { (1..=100).sum::<u32>() });
+        assert_eq!(sum, 5050);
+        let sum = assert_completes_within!(Duration::from_secs(5), iterations = 3, { (1..=100).sum::<u32>() });
+        assert_eq!(sum, 5050);
+    }
+    #[test]
+    fn runs_every_iteration() {
+        let mut runs = 0;
+        let last = assert_completes_within!(Duration::from_secs(5), iterations = 4, {
+            runs += 1;
+            runs
+        });
+        assert_eq!((runs, last), (4, 4));
+    }
+    #[test]
+    fn moves_captured_value() {
+        let v = vec![1, 2, 3];
+        let len = assert_completes_within!(Duration::from_secs(5), { let w = v; w.len() });
+        assert_eq!(len, 3);
+        let v = vec![1, 2, 3];
+        let len = assert_completes_within!(Duration::from_secs(5), { let w = v; w.len() }, "{} is fast", "len");
+        assert_eq!(len, 3);
+    }
+    #[test]
+    fn trailing_comma() {
+        assert_completes_within!(Duration::from_secs(5), {},);
+        assert_completes_within!(Duration::from_secs(5), iterations = 2, {},);
+    }
+    #[test]
+    fn format_string() {
+        assert_completes_within!(Duration::from_secs(5), { 1 + 1 }, "{} is fast", "addition");
+        assert_completes_within!(Duration::from_secs(5), iterations = 2, { 1 + 1 }, "{} is fast", "addition");
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect() {
+        assert_completes_within!(Duration::from_millis(1), { thread::sleep(Duration::from_millis(20)) });
+    }
+    #[test]
+    #[should_panic]
+    fn incorrect_median() {
+        assert_completes_within!(Duration::from_millis(1), iterations = 3, { thread::sleep(Duration::from_millis(5)) });
+    }
+    #[test]
+    #[should_panic(expected = "at least one iteration")]
+    fn no_iterations() {
+        assert_completes_within!(Duration::from_secs(5), iterations = 0, {});
+    }
+}
+
+#[cfg(test)]
+mod messages {
+    use super::over_budget;
+    use crate::assert_panics;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn duration_near() {
+        let elapsed = Duration::from_micros(114_200);
+        assert_panics!(
+            { assert_duration_near!(elapsed, Duration::from_millis(100), tolerance = 10ms) },
+            message == "assertion failed: `elapsed ≈ Duration::from_millis(100)`\n       left: 114.2ms\n      right: 100ms\n difference: +14.2ms\n  tolerance: 10ms"
+        );
+    }
+    #[test]
+    fn before() {
+        let (start, end) = (SystemTime::UNIX_EPOCH, SystemTime::UNIX_EPOCH + Duration::from_micros(1_500));
+        assert_panics!({ assert_before!(end, start) }, message == "assertion failed: `end before start`\n end is 1.5ms after start");
+    }
+    #[test]
+    fn completes_within() {
+        let budget = Duration::from_millis(1);
+        let mut samples = [Duration::from_millis(20)];
+        assert_panics!(
+            { over_budget(&mut samples, budget).unwrap().fail(&["{ work() }", "budget"], None) },
+            message == "assertion failed: `{ work() } <= budget`\n  left: 20ms\n right: 1ms"
+        );
+        let mut samples = [Duration::from_millis(5), Duration::from_millis(4), Duration::from_millis(6)];
+        assert_panics!(
+            { over_budget(&mut samples, budget).unwrap().fail(&["{ work() }", "budget"], Some(format_args!("{}", "slow"))) },
+            message == "assertion failed: `{ work() } <= budget`: slow\n    left: 5ms\n   right: 1ms\n samples: [4ms, 5ms, 6ms]\n    note: left is the median of 3 runs"
+        );
+    }
+}
+
+#[cfg(test)]
+mod human {
+    use super::{lateness, outside, over_budget, tolerance, Human, Samples};
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn units() {
+        assert_eq!(Human(Duration::from_nanos(0)).to_string(), "0ns");
+        assert_eq!(Human(Duration::from_nanos(999)).to_string(), "999ns");
+        assert_eq!(Human(Duration::from_nanos(1_500)).to_string(), "1.5µs");
+        assert_eq!(Human(Duration::from_micros(14_200)).to_string(), "14.2ms");
+        assert_eq!(Human(Duration::from_millis(1_005)).to_string(), "1.005s");
+        assert_eq!(Human(Duration::from_nanos(1_000_000_999)).to_string(), "1s");
+        assert_eq!(Human(Duration::from_secs(3_600)).to_string(), "3600s");
+    }
+    #[test]
+    fn tolerances() {
+        assert_eq!(tolerance("10ms"), Duration::from_millis(10));
+        assert_eq!(tolerance("250us"), Duration::from_micros(250));
+        assert_eq!(tolerance("1.5s"), Duration::from_millis(1_500));
+        assert_eq!(tolerance("7ns"), Duration::from_nanos(7));
+        assert_eq!(tolerance("5.8ms"), Duration::from_micros(5_800));
+        assert_eq!(tolerance("1_500us"), Duration::from_micros(1_500));
+        assert_eq!(tolerance("1.5ns"), Duration::from_nanos(2));
+        assert_eq!(tolerance("0.0000000014s"), Duration::from_nanos(1));
+        assert_eq!(tolerance("18446744073s"), Duration::from_secs(18_446_744_073));
+    }
+    #[test]
+    #[should_panic(expected = "invalid tolerance: expected a number followed by ns, us, ms or s")]
+    fn invalid_unit() {
+        tolerance("10min");
+    }
+    #[test]
+    #[should_panic(expected = "invalid tolerance: expected a number followed by ns, us, ms or s")]
+    fn missing_unit() {
+        tolerance("5");
+    }
+    #[test]
+    #[should_panic(expected = "invalid tolerance: at most 18446744073.709551615s")]
+    fn too_large() {
+        tolerance("18446744074s");
+    }
+    #[test]
+    fn differences() {
+        let (hundred, ten) = (Duration::from_millis(100), Duration::from_millis(10));
+        assert_eq!(outside(Duration::from_micros(114_200), hundred, ten), Some(String::from("+14.2ms")));
+        assert_eq!(outside(Duration::from_millis(89), hundred, ten), Some(String::from("-11ms")));
+        assert_eq!(outside(Duration::from_millis(110), hundred, ten), None);
+    }
+    #[test]
+    fn lateness_messages() {
+        let epoch = SystemTime::UNIX_EPOCH;
+        let later = epoch + Duration::from_micros(1_500);
+        assert_eq!(lateness(later, epoch), Some(String::from("1.5ms after")));
+        assert_eq!(lateness(epoch, epoch), Some(String::from("at the same time as")));
+        assert_eq!(lateness(epoch, later), None);
+    }
+    #[test]
+    fn budgets() {
+        let ms = Duration::from_millis;
+        assert_eq!(over_budget(&mut [ms(3), ms(1), ms(2)], ms(2)), None);
+        let mut samples = [ms(4), ms(1), ms(2), ms(3)];
+        let over = over_budget(&mut samples, ms(2)).unwrap();
+        assert_eq!(over.median, Duration::from_micros(2_500));
+        assert_eq!(over.samples, &[ms(1), ms(2), ms(3), ms(4)]);
+        assert_eq!(Samples(over.samples).to_string(), "[1ms, 2ms, 3ms, 4ms]");
+    }
+}
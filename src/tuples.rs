@@ -31,59 +31,76 @@
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_00th::le_correct' panicked at 'assertion failed: (tuple.0 <= val)
-///     val: 0
-/// tuple.0: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &0` where `value = &tuple.0`
+///   left: 1
+///  right: 0'
 /// ```
 #[macro_export]
 macro_rules! assert_0th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.0 != $val {
-            panic!("assertion failed: (tuple.0 == val)\n    val: {:?}\ntuple.0: {:?}\n",
-                $val,
-                $tuple.0,
-            );
+            $crate::failure::fail("assert_0th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.0`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.0))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".0"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.0, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.0 == $val {
-            panic!("assertion failed: (tuple.0 != val)\n    val: {:?}\ntuple.0: {:?}\n",
-                $val,
-                $tuple.0,
-            );
+            $crate::failure::fail("assert_0th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.0`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.0))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".0"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.0, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.0 >= $val {
-            panic!("assertion failed: (tuple.0 < val)\n    val: {:?}\ntuple.0: {:?}\n",
-                $val,
-                $tuple.0,
-            );
+            $crate::failure::fail("assert_0th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.0`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.0))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".0"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.0, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.0 > $val {
-            panic!("assertion failed: (tuple.0 <= val)\n    val: {:?}\ntuple.0: {:?}\n",
-                $val,
-                $tuple.0,
-            );
+            $crate::failure::fail("assert_0th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.0`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.0))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".0"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.0, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.0 <= $val {
-            panic!("assertion failed: (tuple.0 > val)\n    val: {:?}\ntuple.0: {:?}\n",
-                $val,
-                $tuple.0,
-            );
+            $crate::failure::fail("assert_0th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.0`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.0))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".0"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.0, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.0 < $val {
-            panic!("assertion failed: (tuple.0 >= val)\n    val: {:?}\ntuple.0: {:?}\n",
-                $val,
-                $tuple.0,
-            );
+            $crate::failure::fail("assert_0th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.0`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.0))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".0"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.0, &$val))));
         }
     };
 }
@@ -117,59 +134,76 @@ macro_rules! assert_0th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_01st::le_correct' panicked at 'assertion failed: (tuple.1 <= val)
-///     val: 0
-/// tuple.1: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &1` where `value = &tuple.1`
+///   left: 2
+///  right: 1'
 /// ```
 #[macro_export]
 macro_rules! assert_1st {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.1 != $val {
-            panic!("assertion failed: (tuple.1 == val)\n    val: {:?}\ntuple.1: {:?}\n",
-                $val,
-                $tuple.1,
-            );
+            $crate::failure::fail("assert_1st", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.1`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.1))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".1"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.1, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.1 == $val {
-            panic!("assertion failed: (tuple.1 != val)\n    val: {:?}\ntuple.1: {:?}\n",
-                $val,
-                $tuple.1,
-            );
+            $crate::failure::fail("assert_1st", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.1`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.1))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".1"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.1, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.1 >= $val {
-            panic!("assertion failed: (tuple.1 < val)\n    val: {:?}\ntuple.1: {:?}\n",
-                $val,
-                $tuple.1,
-            );
+            $crate::failure::fail("assert_1st", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.1`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.1))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".1"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.1, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.1 > $val {
-            panic!("assertion failed: (tuple.1 <= val)\n    val: {:?}\ntuple.1: {:?}\n",
-                $val,
-                $tuple.1,
-            );
+            $crate::failure::fail("assert_1st", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.1`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.1))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".1"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.1, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.1 <= $val {
-            panic!("assertion failed: (tuple.1 > val)\n    val: {:?}\ntuple.1: {:?}\n",
-                $val,
-                $tuple.1,
-            );
+            $crate::failure::fail("assert_1st", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.1`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.1))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".1"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.1, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.1 < $val {
-            panic!("assertion failed: (tuple.1 >= val)\n    val: {:?}\ntuple.1: {:?}\n",
-                $val,
-                $tuple.1,
-            );
+            $crate::failure::fail("assert_1st", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.1`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.1))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".1"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.1, &$val))));
         }
     };
 }
@@ -203,59 +237,76 @@ macro_rules! assert_1st {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_02nd::le_correct' panicked at 'assertion failed: (tuple.2 <= val)
-///     val: 0
-/// tuple.2: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &2` where `value = &tuple.2`
+///   left: 3
+///  right: 2'
 /// ```
 #[macro_export]
 macro_rules! assert_2nd {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.2 != $val {
-            panic!("assertion failed: (tuple.2 == val)\n    val: {:?}\ntuple.2: {:?}\n",
-                $val,
-                $tuple.2,
-            );
+            $crate::failure::fail("assert_2nd", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.2`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.2))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".2"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.2, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.2 == $val {
-            panic!("assertion failed: (tuple.2 != val)\n    val: {:?}\ntuple.2: {:?}\n",
-                $val,
-                $tuple.2,
-            );
+            $crate::failure::fail("assert_2nd", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.2`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.2))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".2"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.2, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.2 >= $val {
-            panic!("assertion failed: (tuple.2 < val)\n    val: {:?}\ntuple.2: {:?}\n",
-                $val,
-                $tuple.2,
-            );
+            $crate::failure::fail("assert_2nd", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.2`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.2))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".2"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.2, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.2 > $val {
-            panic!("assertion failed: (tuple.2 <= val)\n    val: {:?}\ntuple.2: {:?}\n",
-                $val,
-                $tuple.2,
-            );
+            $crate::failure::fail("assert_2nd", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.2`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.2))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".2"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.2, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.2 <= $val {
-            panic!("assertion failed: (tuple.2 > val)\n    val: {:?}\ntuple.2: {:?}\n",
-                $val,
-                $tuple.2,
-            );
+            $crate::failure::fail("assert_2nd", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.2`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.2))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".2"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.2, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.2 < $val {
-            panic!("assertion failed: (tuple.2 >= val)\n    val: {:?}\ntuple.2: {:?}\n",
-                $val,
-                $tuple.2,
-            );
+            $crate::failure::fail("assert_2nd", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.2`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.2))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".2"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.2, &$val))));
         }
     };
 }
@@ -289,59 +340,76 @@ macro_rules! assert_2nd {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_03rd::le_correct' panicked at 'assertion failed: (tuple.3 <= val)
-///     val: 0
-/// tuple.3: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &3` where `value = &tuple.3`
+///   left: 4
+///  right: 3'
 /// ```
 #[macro_export]
 macro_rules! assert_3rd {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.3 != $val {
-            panic!("assertion failed: (tuple.3 == val)\n    val: {:?}\ntuple.3: {:?}\n",
-                $val,
-                $tuple.3,
-            );
+            $crate::failure::fail("assert_3rd", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.3`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.3))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".3"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.3, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.3 == $val {
-            panic!("assertion failed: (tuple.3 != val)\n    val: {:?}\ntuple.3: {:?}\n",
-                $val,
-                $tuple.3,
-            );
+            $crate::failure::fail("assert_3rd", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.3`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.3))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".3"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.3, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.3 >= $val {
-            panic!("assertion failed: (tuple.3 < val)\n    val: {:?}\ntuple.3: {:?}\n",
-                $val,
-                $tuple.3,
-            );
+            $crate::failure::fail("assert_3rd", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.3`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.3))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".3"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.3, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.3 > $val {
-            panic!("assertion failed: (tuple.3 <= val)\n    val: {:?}\ntuple.3: {:?}\n",
-                $val,
-                $tuple.3,
-            );
+            $crate::failure::fail("assert_3rd", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.3`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.3))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".3"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.3, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.3 <= $val {
-            panic!("assertion failed: (tuple.3 > val)\n    val: {:?}\ntuple.3: {:?}\n",
-                $val,
-                $tuple.3,
-            );
+            $crate::failure::fail("assert_3rd", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.3`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.3))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".3"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.3, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.3 < $val {
-            panic!("assertion failed: (tuple.3 >= val)\n    val: {:?}\ntuple.3: {:?}\n",
-                $val,
-                $tuple.3,
-            );
+            $crate::failure::fail("assert_3rd", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.3`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.3))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".3"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.3, &$val))));
         }
     };
 }
@@ -375,59 +443,76 @@ macro_rules! assert_3rd {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_04th::le_correct' panicked at 'assertion failed: (tuple.4 <= val)
-///     val: 0
-/// tuple.4: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &4` where `value = &tuple.4`
+///   left: 5
+///  right: 4'
 /// ```
 #[macro_export]
 macro_rules! assert_4th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.4 != $val {
-            panic!("assertion failed: (tuple.4 == val)\n    val: {:?}\ntuple.4: {:?}\n",
-                $val,
-                $tuple.4,
-            );
+            $crate::failure::fail("assert_4th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.4`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.4))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".4"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.4, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.4 == $val {
-            panic!("assertion failed: (tuple.4 != val)\n    val: {:?}\ntuple.4: {:?}\n",
-                $val,
-                $tuple.4,
-            );
+            $crate::failure::fail("assert_4th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.4`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.4))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".4"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.4, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.4 >= $val {
-            panic!("assertion failed: (tuple.4 < val)\n    val: {:?}\ntuple.4: {:?}\n",
-                $val,
-                $tuple.4,
-            );
+            $crate::failure::fail("assert_4th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.4`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.4))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".4"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.4, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.4 > $val {
-            panic!("assertion failed: (tuple.4 <= val)\n    val: {:?}\ntuple.4: {:?}\n",
-                $val,
-                $tuple.4,
-            );
+            $crate::failure::fail("assert_4th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.4`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.4))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".4"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.4, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.4 <= $val {
-            panic!("assertion failed: (tuple.4 > val)\n    val: {:?}\ntuple.4: {:?}\n",
-                $val,
-                $tuple.4,
-            );
+            $crate::failure::fail("assert_4th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.4`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.4))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".4"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.4, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.4 < $val {
-            panic!("assertion failed: (tuple.4 >= val)\n    val: {:?}\ntuple.4: {:?}\n",
-                $val,
-                $tuple.4,
-            );
+            $crate::failure::fail("assert_4th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.4`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.4))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".4"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.4, &$val))));
         }
     };
 }
@@ -461,59 +546,76 @@ macro_rules! assert_4th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_05th::le_correct' panicked at 'assertion failed: (tuple.5 <= val)
-///     val: 0
-/// tuple.5: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &5` where `value = &tuple.5`
+///   left: 6
+///  right: 5'
 /// ```
 #[macro_export]
 macro_rules! assert_5th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.5 != $val {
-            panic!("assertion failed: (tuple.5 == val)\n    val: {:?}\ntuple.5: {:?}\n",
-                $val,
-                $tuple.5,
-            );
+            $crate::failure::fail("assert_5th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.5`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.5))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".5"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.5, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.5 == $val {
-            panic!("assertion failed: (tuple.5 != val)\n    val: {:?}\ntuple.5: {:?}\n",
-                $val,
-                $tuple.5,
-            );
+            $crate::failure::fail("assert_5th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.5`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.5))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".5"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.5, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.5 >= $val {
-            panic!("assertion failed: (tuple.5 < val)\n    val: {:?}\ntuple.5: {:?}\n",
-                $val,
-                $tuple.5,
-            );
+            $crate::failure::fail("assert_5th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.5`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.5))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".5"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.5, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.5 > $val {
-            panic!("assertion failed: (tuple.5 <= val)\n    val: {:?}\ntuple.5: {:?}\n",
-                $val,
-                $tuple.5,
-            );
+            $crate::failure::fail("assert_5th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.5`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.5))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".5"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.5, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.5 <= $val {
-            panic!("assertion failed: (tuple.5 > val)\n    val: {:?}\ntuple.5: {:?}\n",
-                $val,
-                $tuple.5,
-            );
+            $crate::failure::fail("assert_5th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.5`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.5))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".5"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.5, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.5 < $val {
-            panic!("assertion failed: (tuple.5 >= val)\n    val: {:?}\ntuple.5: {:?}\n",
-                $val,
-                $tuple.5,
-            );
+            $crate::failure::fail("assert_5th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.5`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.5))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".5"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.5, &$val))));
         }
     };
 }
@@ -547,59 +649,76 @@ macro_rules! assert_5th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_06th::le_correct' panicked at 'assertion failed: (tuple.6 <= val)
-///     val: 0
-/// tuple.6: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &6` where `value = &tuple.6`
+///   left: 7
+///  right: 6'
 /// ```
 #[macro_export]
 macro_rules! assert_6th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.6 != $val {
-            panic!("assertion failed: (tuple.6 == val)\n    val: {:?}\ntuple.6: {:?}\n",
-                $val,
-                $tuple.6,
-            );
+            $crate::failure::fail("assert_6th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.6`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.6))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".6"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.6, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.6 == $val {
-            panic!("assertion failed: (tuple.6 != val)\n    val: {:?}\ntuple.6: {:?}\n",
-                $val,
-                $tuple.6,
-            );
+            $crate::failure::fail("assert_6th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.6`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.6))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".6"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.6, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.6 >= $val {
-            panic!("assertion failed: (tuple.6 < val)\n    val: {:?}\ntuple.6: {:?}\n",
-                $val,
-                $tuple.6,
-            );
+            $crate::failure::fail("assert_6th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.6`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.6))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".6"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.6, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.6 > $val {
-            panic!("assertion failed: (tuple.6 <= val)\n    val: {:?}\ntuple.6: {:?}\n",
-                $val,
-                $tuple.6,
-            );
+            $crate::failure::fail("assert_6th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.6`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.6))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".6"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.6, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.6 <= $val {
-            panic!("assertion failed: (tuple.6 > val)\n    val: {:?}\ntuple.6: {:?}\n",
-                $val,
-                $tuple.6,
-            );
+            $crate::failure::fail("assert_6th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.6`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.6))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".6"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.6, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.6 < $val {
-            panic!("assertion failed: (tuple.6 >= val)\n    val: {:?}\ntuple.6: {:?}\n",
-                $val,
-                $tuple.6,
-            );
+            $crate::failure::fail("assert_6th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.6`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.6))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".6"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.6, &$val))));
         }
     };
 }
@@ -633,59 +752,76 @@ macro_rules! assert_6th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_07th::le_correct' panicked at 'assertion failed: (tuple.7 <= val)
-///     val: 0
-/// tuple.7: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &7` where `value = &tuple.7`
+///   left: 8
+///  right: 7'
 /// ```
 #[macro_export]
 macro_rules! assert_7th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.7 != $val {
-            panic!("assertion failed: (tuple.7 == val)\n    val: {:?}\ntuple.7: {:?}\n",
-                $val,
-                $tuple.7,
-            );
+            $crate::failure::fail("assert_7th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.7`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.7))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".7"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.7, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.7 == $val {
-            panic!("assertion failed: (tuple.7 != val)\n    val: {:?}\ntuple.7: {:?}\n",
-                $val,
-                $tuple.7,
-            );
+            $crate::failure::fail("assert_7th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.7`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.7))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".7"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.7, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.7 >= $val {
-            panic!("assertion failed: (tuple.7 < val)\n    val: {:?}\ntuple.7: {:?}\n",
-                $val,
-                $tuple.7,
-            );
+            $crate::failure::fail("assert_7th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.7`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.7))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".7"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.7, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.7 > $val {
-            panic!("assertion failed: (tuple.7 <= val)\n    val: {:?}\ntuple.7: {:?}\n",
-                $val,
-                $tuple.7,
-            );
+            $crate::failure::fail("assert_7th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.7`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.7))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".7"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.7, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.7 <= $val {
-            panic!("assertion failed: (tuple.7 > val)\n    val: {:?}\ntuple.7: {:?}\n",
-                $val,
-                $tuple.7,
-            );
+            $crate::failure::fail("assert_7th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.7`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.7))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".7"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.7, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.7 < $val {
-            panic!("assertion failed: (tuple.7 >= val)\n    val: {:?}\ntuple.7: {:?}\n",
-                $val,
-                $tuple.7,
-            );
+            $crate::failure::fail("assert_7th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.7`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.7))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".7"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.7, &$val))));
         }
     };
 }
@@ -719,59 +855,76 @@ macro_rules! assert_7th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_08th::le_correct' panicked at 'assertion failed: (tuple.8 <= val)
-///     val: 0
-/// tuple.8: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &8` where `value = &tuple.8`
+///   left: 9
+///  right: 8'
 /// ```
 #[macro_export]
 macro_rules! assert_8th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.8 != $val {
-            panic!("assertion failed: (tuple.8 == val)\n    val: {:?}\ntuple.8: {:?}\n",
-                $val,
-                $tuple.8,
-            );
+            $crate::failure::fail("assert_8th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.8`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.8))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".8"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.8, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.8 == $val {
-            panic!("assertion failed: (tuple.8 != val)\n    val: {:?}\ntuple.8: {:?}\n",
-                $val,
-                $tuple.8,
-            );
+            $crate::failure::fail("assert_8th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.8`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.8))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".8"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.8, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.8 >= $val {
-            panic!("assertion failed: (tuple.8 < val)\n    val: {:?}\ntuple.8: {:?}\n",
-                $val,
-                $tuple.8,
-            );
+            $crate::failure::fail("assert_8th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.8`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.8))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".8"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.8, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.8 > $val {
-            panic!("assertion failed: (tuple.8 <= val)\n    val: {:?}\ntuple.8: {:?}\n",
-                $val,
-                $tuple.8,
-            );
+            $crate::failure::fail("assert_8th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.8`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.8))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".8"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.8, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.8 <= $val {
-            panic!("assertion failed: (tuple.8 > val)\n    val: {:?}\ntuple.8: {:?}\n",
-                $val,
-                $tuple.8,
-            );
+            $crate::failure::fail("assert_8th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.8`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.8))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".8"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.8, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.8 < $val {
-            panic!("assertion failed: (tuple.8 >= val)\n    val: {:?}\ntuple.8: {:?}\n",
-                $val,
-                $tuple.8,
-            );
+            $crate::failure::fail("assert_8th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.8`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.8))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".8"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.8, &$val))));
         }
     };
 }
@@ -805,59 +958,76 @@ macro_rules! assert_8th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_09th::le_correct' panicked at 'assertion failed: (tuple.9 <= val)
-///     val: 0
-/// tuple.9: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &9` where `value = &tuple.9`
+///   left: 10
+///  right: 9'
 /// ```
 #[macro_export]
 macro_rules! assert_9th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.9 != $val {
-            panic!("assertion failed: (tuple.9 == val)\n    val: {:?}\ntuple.9: {:?}\n",
-                $val,
-                $tuple.9,
-            );
+            $crate::failure::fail("assert_9th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.9`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.9))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".9"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.9, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.9 == $val {
-            panic!("assertion failed: (tuple.9 != val)\n    val: {:?}\ntuple.9: {:?}\n",
-                $val,
-                $tuple.9,
-            );
+            $crate::failure::fail("assert_9th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.9`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.9))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".9"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.9, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.9 >= $val {
-            panic!("assertion failed: (tuple.9 < val)\n    val: {:?}\ntuple.9: {:?}\n",
-                $val,
-                $tuple.9,
-            );
+            $crate::failure::fail("assert_9th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.9`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.9))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".9"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.9, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.9 > $val {
-            panic!("assertion failed: (tuple.9 <= val)\n    val: {:?}\ntuple.9: {:?}\n",
-                $val,
-                $tuple.9,
-            );
+            $crate::failure::fail("assert_9th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.9`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.9))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".9"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.9, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.9 <= $val {
-            panic!("assertion failed: (tuple.9 > val)\n    val: {:?}\ntuple.9: {:?}\n",
-                $val,
-                $tuple.9,
-            );
+            $crate::failure::fail("assert_9th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.9`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.9))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".9"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.9, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.9 < $val {
-            panic!("assertion failed: (tuple.9 >= val)\n    val: {:?}\ntuple.9: {:?}\n",
-                $val,
-                $tuple.9,
-            );
+            $crate::failure::fail("assert_9th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.9`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.9))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".9"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.9, &$val))));
         }
     };
 }
@@ -891,59 +1061,76 @@ macro_rules! assert_9th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_10th::le_correct' panicked at 'assertion failed: (tuple.10 <= val)
-///      val: 0
-/// tuple.10: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &10` where `value = &tuple.10`
+///   left: 11
+///  right: 10'
 /// ```
 #[macro_export]
 macro_rules! assert_10th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.10 != $val {
-            panic!("assertion failed: (tuple.10 == val)\n     val: {:?}\ntuple.10: {:?}\n",
-                $val,
-                $tuple.10,
-            );
+            $crate::failure::fail("assert_10th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.10`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.10))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".10"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.10, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.10 == $val {
-            panic!("assertion failed: (tuple.10 != val)\n     val: {:?}\ntuple.10: {:?}\n",
-                $val,
-                $tuple.10,
-            );
+            $crate::failure::fail("assert_10th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.10`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.10))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".10"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.10, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.10 >= $val {
-            panic!("assertion failed: (tuple.10 < val)\n     val: {:?}\ntuple.10: {:?}\n",
-                $val,
-                $tuple.10,
-            );
+            $crate::failure::fail("assert_10th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.10`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.10))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".10"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.10, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.10 > $val {
-            panic!("assertion failed: (tuple.10 <= val)\n     val: {:?}\ntuple.10: {:?}\n",
-                $val,
-                $tuple.10,
-            );
+            $crate::failure::fail("assert_10th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.10`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.10))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".10"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.10, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.10 <= $val {
-            panic!("assertion failed: (tuple.10 > val)\n     val: {:?}\ntuple.10: {:?}\n",
-                $val,
-                $tuple.10,
-            );
+            $crate::failure::fail("assert_10th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.10`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.10))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".10"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.10, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.10 < $val {
-            panic!("assertion failed: (tuple.10 >= val)\n     val: {:?}\ntuple.10: {:?}\n",
-                $val,
-                $tuple.10,
-            );
+            $crate::failure::fail("assert_10th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.10`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.10))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".10"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.10, &$val))));
         }
     };
 }
@@ -977,59 +1164,76 @@ macro_rules! assert_10th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_11th::le_correct' panicked at 'assertion failed: (tuple.11 <= val)
-///      val: 0
-/// tuple.11: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &11` where `value = &tuple.11`
+///   left: 12
+///  right: 11'
 /// ```
 #[macro_export]
 macro_rules! assert_11th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.11 != $val {
-            panic!("assertion failed: (tuple.11 == val)\n     val: {:?}\ntuple.11: {:?}\n",
-                $val,
-                $tuple.11,
-            );
+            $crate::failure::fail("assert_11th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.11`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.11))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".11"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.11, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.11 == $val {
-            panic!("assertion failed: (tuple.11 != val)\n     val: {:?}\ntuple.11: {:?}\n",
-                $val,
-                $tuple.11,
-            );
+            $crate::failure::fail("assert_11th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.11`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.11))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".11"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.11, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.11 >= $val {
-            panic!("assertion failed: (tuple.11 < val)\n     val: {:?}\ntuple.11: {:?}\n",
-                $val,
-                $tuple.11,
-            );
+            $crate::failure::fail("assert_11th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.11`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.11))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".11"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.11, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.11 > $val {
-            panic!("assertion failed: (tuple.11 <= val)\n     val: {:?}\ntuple.11: {:?}\n",
-                $val,
-                $tuple.11,
-            );
+            $crate::failure::fail("assert_11th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.11`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.11))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".11"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.11, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.11 <= $val {
-            panic!("assertion failed: (tuple.11 > val)\n     val: {:?}\ntuple.11: {:?}\n",
-                $val,
-                $tuple.11,
-            );
+            $crate::failure::fail("assert_11th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.11`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.11))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".11"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.11, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.11 < $val {
-            panic!("assertion failed: (tuple.11 >= val)\n     val: {:?}\ntuple.11: {:?}\n",
-                $val,
-                $tuple.11,
-            );
+            $crate::failure::fail("assert_11th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.11`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.11))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".11"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.11, &$val))));
         }
     };
 }
@@ -1063,59 +1267,76 @@ macro_rules! assert_11th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_12th::le_correct' panicked at 'assertion failed: (tuple.12 <= val)
-///      val: 0
-/// tuple.12: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &12` where `value = &tuple.12`
+///   left: 13
+///  right: 12'
 /// ```
 #[macro_export]
 macro_rules! assert_12th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.12 != $val {
-            panic!("assertion failed: (tuple.12 == val)\n     val: {:?}\ntuple.12: {:?}\n",
-                $val,
-                $tuple.12,
-            );
+            $crate::failure::fail("assert_12th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.12`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.12))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".12"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.12, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.12 == $val {
-            panic!("assertion failed: (tuple.12 != val)\n     val: {:?}\ntuple.12: {:?}\n",
-                $val,
-                $tuple.12,
-            );
+            $crate::failure::fail("assert_12th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.12`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.12))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".12"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.12, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.12 >= $val {
-            panic!("assertion failed: (tuple.12 < val)\n     val: {:?}\ntuple.12: {:?}\n",
-                $val,
-                $tuple.12,
-            );
+            $crate::failure::fail("assert_12th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.12`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.12))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".12"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.12, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.12 > $val {
-            panic!("assertion failed: (tuple.12 <= val)\n     val: {:?}\ntuple.12: {:?}\n",
-                $val,
-                $tuple.12,
-            );
+            $crate::failure::fail("assert_12th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.12`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.12))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".12"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.12, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.12 <= $val {
-            panic!("assertion failed: (tuple.12 > val)\n     val: {:?}\ntuple.12: {:?}\n",
-                $val,
-                $tuple.12,
-            );
+            $crate::failure::fail("assert_12th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.12`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.12))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".12"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.12, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.12 < $val {
-            panic!("assertion failed: (tuple.12 >= val)\n     val: {:?}\ntuple.12: {:?}\n",
-                $val,
-                $tuple.12,
-            );
+            $crate::failure::fail("assert_12th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.12`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.12))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".12"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.12, &$val))));
         }
     };
 }
@@ -1149,59 +1370,76 @@ macro_rules! assert_12th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_13th::le_correct' panicked at 'assertion failed: (tuple.13 <= val)
-///      val: 0
-/// tuple.13: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &13` where `value = &tuple.13`
+///   left: 14
+///  right: 13'
 /// ```
 #[macro_export]
 macro_rules! assert_13th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.13 != $val {
-            panic!("assertion failed: (tuple.13 == val)\n     val: {:?}\ntuple.13: {:?}\n",
-                $val,
-                $tuple.13,
-            );
+            $crate::failure::fail("assert_13th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.13`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.13))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".13"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.13, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.13 == $val {
-            panic!("assertion failed: (tuple.13 != val)\n     val: {:?}\ntuple.13: {:?}\n",
-                $val,
-                $tuple.13,
-            );
+            $crate::failure::fail("assert_13th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.13`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.13))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".13"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.13, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.13 >= $val {
-            panic!("assertion failed: (tuple.13 < val)\n     val: {:?}\ntuple.13: {:?}\n",
-                $val,
-                $tuple.13,
-            );
+            $crate::failure::fail("assert_13th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.13`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.13))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".13"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.13, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.13 > $val {
-            panic!("assertion failed: (tuple.13 <= val)\n     val: {:?}\ntuple.13: {:?}\n",
-                $val,
-                $tuple.13,
-            );
+            $crate::failure::fail("assert_13th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.13`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.13))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".13"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.13, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.13 <= $val {
-            panic!("assertion failed: (tuple.13 > val)\n     val: {:?}\ntuple.13: {:?}\n",
-                $val,
-                $tuple.13,
-            );
+            $crate::failure::fail("assert_13th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.13`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.13))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".13"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.13, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.13 < $val {
-            panic!("assertion failed: (tuple.13 >= val)\n     val: {:?}\ntuple.13: {:?}\n",
-                $val,
-                $tuple.13,
-            );
+            $crate::failure::fail("assert_13th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.13`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.13))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".13"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.13, &$val))));
         }
     };
 }
@@ -1235,59 +1473,76 @@ macro_rules! assert_13th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_14th::le_correct' panicked at 'assertion failed: (tuple.14 <= val)
-///      val: 0
-/// tuple.14: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &14` where `value = &tuple.14`
+///   left: 15
+///  right: 14'
 /// ```
 #[macro_export]
 macro_rules! assert_14th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.14 != $val {
-            panic!("assertion failed: (tuple.14 == val)\n     val: {:?}\ntuple.14: {:?}\n",
-                $val,
-                $tuple.14,
-            );
+            $crate::failure::fail("assert_14th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.14`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.14))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".14"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.14, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.14 == $val {
-            panic!("assertion failed: (tuple.14 != val)\n     val: {:?}\ntuple.14: {:?}\n",
-                $val,
-                $tuple.14,
-            );
+            $crate::failure::fail("assert_14th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.14`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.14))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".14"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.14, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.14 >= $val {
-            panic!("assertion failed: (tuple.14 < val)\n     val: {:?}\ntuple.14: {:?}\n",
-                $val,
-                $tuple.14,
-            );
+            $crate::failure::fail("assert_14th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.14`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.14))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".14"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.14, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.14 > $val {
-            panic!("assertion failed: (tuple.14 <= val)\n     val: {:?}\ntuple.14: {:?}\n",
-                $val,
-                $tuple.14,
-            );
+            $crate::failure::fail("assert_14th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.14`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.14))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".14"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.14, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.14 <= $val {
-            panic!("assertion failed: (tuple.14 > val)\n     val: {:?}\ntuple.14: {:?}\n",
-                $val,
-                $tuple.14,
-            );
+            $crate::failure::fail("assert_14th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.14`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.14))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".14"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.14, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.14 < $val {
-            panic!("assertion failed: (tuple.14 >= val)\n     val: {:?}\ntuple.14: {:?}\n",
-                $val,
-                $tuple.14,
-            );
+            $crate::failure::fail("assert_14th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.14`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.14))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".14"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.14, &$val))));
         }
     };
 }
@@ -1321,59 +1576,76 @@ macro_rules! assert_14th {
 /// ### Example Error Messages 
 ///
 /// ```text 
-/// thread 'tuples::_15th::le_correct' panicked at 'assertion failed: (tuple.15 <= val)
-///      val: 0
-/// tuple.15: 1
-/// ', src/tuples.rs:2162:9
+/// thread 'main' panicked at 'assertion failed: `value <= &15` where `value = &tuple.15`
+///   left: 16
+///  right: 15'
 /// ```
 #[macro_export]
 macro_rules! assert_15th {
     ($tuple:expr, value == $val:expr) => {
         if &$tuple.15 != $val {
-            panic!("assertion failed: (tuple.15 == val)\n     val: {:?}\ntuple.15: {:?}\n",
-                $val,
-                $tuple.15,
-            );
+            $crate::failure::fail("assert_15th", &$crate::report::Report::new(
+                format_args!("`value == {}` where `value = {}.15`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.15))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("==", &[concat!(stringify!($tuple), ".15"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.15, &$val))));
         }
     };
     ($tuple:expr, value != $val:expr) => {
         if &$tuple.15 == $val {
-            panic!("assertion failed: (tuple.15 != val)\n     val: {:?}\ntuple.15: {:?}\n",
-                $val,
-                $tuple.15,
-            );
+            $crate::failure::fail("assert_15th", &$crate::report::Report::new(
+                format_args!("`value != {}` where `value = {}.15`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.15))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("!=", &[concat!(stringify!($tuple), ".15"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.15, &$val))));
         }
     };
     ($tuple:expr, value < $val:expr) => {
         if &$tuple.15 >= $val {
-            panic!("assertion failed: (tuple.15 < val)\n     val: {:?}\ntuple.15: {:?}\n",
-                $val,
-                $tuple.15,
-            );
+            $crate::failure::fail("assert_15th", &$crate::report::Report::new(
+                format_args!("`value < {}` where `value = {}.15`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.15))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<", &[concat!(stringify!($tuple), ".15"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.15, &$val))));
         }
     };
     ($tuple:expr, value <= $val:expr) => {
         if &$tuple.15 > $val {
-            panic!("assertion failed: (tuple.15 <= val)\n     val: {:?}\ntuple.15: {:?}\n",
-                $val,
-                $tuple.15,
-            );
+            $crate::failure::fail("assert_15th", &$crate::report::Report::new(
+                format_args!("`value <= {}` where `value = {}.15`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.15))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source("<=", &[concat!(stringify!($tuple), ".15"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.15, &$val))));
         }
     };
     ($tuple:expr, value > $val:expr) => {
         if &$tuple.15 <= $val {
-            panic!("assertion failed: (tuple.15 > val)\n     val: {:?}\ntuple.15: {:?}\n",
-                $val,
-                $tuple.15,
-            );
+            $crate::failure::fail("assert_15th", &$crate::report::Report::new(
+                format_args!("`value > {}` where `value = {}.15`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.15))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">", &[concat!(stringify!($tuple), ".15"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.15, &$val))));
         }
     };
     ($tuple:expr, value >= $val:expr) => {
         if &$tuple.15 < $val {
-            panic!("assertion failed: (tuple.15 >= val)\n     val: {:?}\ntuple.15: {:?}\n",
-                $val,
-                $tuple.15,
-            );
+            $crate::failure::fail("assert_15th", &$crate::report::Report::new(
+                format_args!("`value >= {}` where `value = {}.15`", stringify!($val), stringify!($tuple)),
+                &[
+                    $crate::report::Field::actual(format_args!("left"), format_args!("{:?}", $crate::report::Pretty(&$tuple.15))),
+                    $crate::report::Field::expected(format_args!("right"), format_args!("{:?}", $crate::report::Pretty(&$val))),
+                ],
+            ).source(">=", &[concat!(stringify!($tuple), ".15"), stringify!($val)]).details(format_args!("{}", $crate::report::Diff(&$tuple.15, &$val))));
         }
     };
 }
@@ -2706,4 +2978,185 @@ mod _00th {
         let tuple = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, "Hello");
         assert_0th!(&tuple, value >= &2);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod messages {
+    use crate::assert_panics;
+
+    #[test]
+    fn _00th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_0th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.0`\n  left: 0\n right: 99");
+        assert_panics!({ assert_0th!(tuple, value != &0) }, message == "assertion failed: `value != &0` where `value = tuple.0`\n  left: 0\n right: 0");
+        assert_panics!({ assert_0th!(tuple, value < &0) }, message == "assertion failed: `value < &0` where `value = tuple.0`\n  left: 0\n right: 0");
+        assert_panics!({ assert_0th!(tuple, value <= &-1) }, message == "assertion failed: `value <= &-1` where `value = tuple.0`\n  left: 0\n right: -1");
+        assert_panics!({ assert_0th!(tuple, value > &0) }, message == "assertion failed: `value > &0` where `value = tuple.0`\n  left: 0\n right: 0");
+        assert_panics!({ assert_0th!(tuple, value >= &1) }, message == "assertion failed: `value >= &1` where `value = tuple.0`\n  left: 0\n right: 1");
+    }
+
+    #[test]
+    fn _01st() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_1st!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.1`\n  left: 1\n right: 99");
+        assert_panics!({ assert_1st!(tuple, value != &1) }, message == "assertion failed: `value != &1` where `value = tuple.1`\n  left: 1\n right: 1");
+        assert_panics!({ assert_1st!(tuple, value < &1) }, message == "assertion failed: `value < &1` where `value = tuple.1`\n  left: 1\n right: 1");
+        assert_panics!({ assert_1st!(tuple, value <= &0) }, message == "assertion failed: `value <= &0` where `value = tuple.1`\n  left: 1\n right: 0");
+        assert_panics!({ assert_1st!(tuple, value > &1) }, message == "assertion failed: `value > &1` where `value = tuple.1`\n  left: 1\n right: 1");
+        assert_panics!({ assert_1st!(tuple, value >= &2) }, message == "assertion failed: `value >= &2` where `value = tuple.1`\n  left: 1\n right: 2");
+    }
+
+    #[test]
+    fn _02nd() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_2nd!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.2`\n  left: 2\n right: 99");
+        assert_panics!({ assert_2nd!(tuple, value != &2) }, message == "assertion failed: `value != &2` where `value = tuple.2`\n  left: 2\n right: 2");
+        assert_panics!({ assert_2nd!(tuple, value < &2) }, message == "assertion failed: `value < &2` where `value = tuple.2`\n  left: 2\n right: 2");
+        assert_panics!({ assert_2nd!(tuple, value <= &1) }, message == "assertion failed: `value <= &1` where `value = tuple.2`\n  left: 2\n right: 1");
+        assert_panics!({ assert_2nd!(tuple, value > &2) }, message == "assertion failed: `value > &2` where `value = tuple.2`\n  left: 2\n right: 2");
+        assert_panics!({ assert_2nd!(tuple, value >= &3) }, message == "assertion failed: `value >= &3` where `value = tuple.2`\n  left: 2\n right: 3");
+    }
+
+    #[test]
+    fn _03rd() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_3rd!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.3`\n  left: 3\n right: 99");
+        assert_panics!({ assert_3rd!(tuple, value != &3) }, message == "assertion failed: `value != &3` where `value = tuple.3`\n  left: 3\n right: 3");
+        assert_panics!({ assert_3rd!(tuple, value < &3) }, message == "assertion failed: `value < &3` where `value = tuple.3`\n  left: 3\n right: 3");
+        assert_panics!({ assert_3rd!(tuple, value <= &2) }, message == "assertion failed: `value <= &2` where `value = tuple.3`\n  left: 3\n right: 2");
+        assert_panics!({ assert_3rd!(tuple, value > &3) }, message == "assertion failed: `value > &3` where `value = tuple.3`\n  left: 3\n right: 3");
+        assert_panics!({ assert_3rd!(tuple, value >= &4) }, message == "assertion failed: `value >= &4` where `value = tuple.3`\n  left: 3\n right: 4");
+    }
+
+    #[test]
+    fn _04th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_4th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.4`\n  left: 4\n right: 99");
+        assert_panics!({ assert_4th!(tuple, value != &4) }, message == "assertion failed: `value != &4` where `value = tuple.4`\n  left: 4\n right: 4");
+        assert_panics!({ assert_4th!(tuple, value < &4) }, message == "assertion failed: `value < &4` where `value = tuple.4`\n  left: 4\n right: 4");
+        assert_panics!({ assert_4th!(tuple, value <= &3) }, message == "assertion failed: `value <= &3` where `value = tuple.4`\n  left: 4\n right: 3");
+        assert_panics!({ assert_4th!(tuple, value > &4) }, message == "assertion failed: `value > &4` where `value = tuple.4`\n  left: 4\n right: 4");
+        assert_panics!({ assert_4th!(tuple, value >= &5) }, message == "assertion failed: `value >= &5` where `value = tuple.4`\n  left: 4\n right: 5");
+    }
+
+    #[test]
+    fn _05th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_5th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.5`\n  left: 5\n right: 99");
+        assert_panics!({ assert_5th!(tuple, value != &5) }, message == "assertion failed: `value != &5` where `value = tuple.5`\n  left: 5\n right: 5");
+        assert_panics!({ assert_5th!(tuple, value < &5) }, message == "assertion failed: `value < &5` where `value = tuple.5`\n  left: 5\n right: 5");
+        assert_panics!({ assert_5th!(tuple, value <= &4) }, message == "assertion failed: `value <= &4` where `value = tuple.5`\n  left: 5\n right: 4");
+        assert_panics!({ assert_5th!(tuple, value > &5) }, message == "assertion failed: `value > &5` where `value = tuple.5`\n  left: 5\n right: 5");
+        assert_panics!({ assert_5th!(tuple, value >= &6) }, message == "assertion failed: `value >= &6` where `value = tuple.5`\n  left: 5\n right: 6");
+    }
+
+    #[test]
+    fn _06th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_6th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.6`\n  left: 6\n right: 99");
+        assert_panics!({ assert_6th!(tuple, value != &6) }, message == "assertion failed: `value != &6` where `value = tuple.6`\n  left: 6\n right: 6");
+        assert_panics!({ assert_6th!(tuple, value < &6) }, message == "assertion failed: `value < &6` where `value = tuple.6`\n  left: 6\n right: 6");
+        assert_panics!({ assert_6th!(tuple, value <= &5) }, message == "assertion failed: `value <= &5` where `value = tuple.6`\n  left: 6\n right: 5");
+        assert_panics!({ assert_6th!(tuple, value > &6) }, message == "assertion failed: `value > &6` where `value = tuple.6`\n  left: 6\n right: 6");
+        assert_panics!({ assert_6th!(tuple, value >= &7) }, message == "assertion failed: `value >= &7` where `value = tuple.6`\n  left: 6\n right: 7");
+    }
+
+    #[test]
+    fn _07th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_7th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.7`\n  left: 7\n right: 99");
+        assert_panics!({ assert_7th!(tuple, value != &7) }, message == "assertion failed: `value != &7` where `value = tuple.7`\n  left: 7\n right: 7");
+        assert_panics!({ assert_7th!(tuple, value < &7) }, message == "assertion failed: `value < &7` where `value = tuple.7`\n  left: 7\n right: 7");
+        assert_panics!({ assert_7th!(tuple, value <= &6) }, message == "assertion failed: `value <= &6` where `value = tuple.7`\n  left: 7\n right: 6");
+        assert_panics!({ assert_7th!(tuple, value > &7) }, message == "assertion failed: `value > &7` where `value = tuple.7`\n  left: 7\n right: 7");
+        assert_panics!({ assert_7th!(tuple, value >= &8) }, message == "assertion failed: `value >= &8` where `value = tuple.7`\n  left: 7\n right: 8");
+    }
+
+    #[test]
+    fn _08th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_8th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.8`\n  left: 8\n right: 99");
+        assert_panics!({ assert_8th!(tuple, value != &8) }, message == "assertion failed: `value != &8` where `value = tuple.8`\n  left: 8\n right: 8");
+        assert_panics!({ assert_8th!(tuple, value < &8) }, message == "assertion failed: `value < &8` where `value = tuple.8`\n  left: 8\n right: 8");
+        assert_panics!({ assert_8th!(tuple, value <= &7) }, message == "assertion failed: `value <= &7` where `value = tuple.8`\n  left: 8\n right: 7");
+        assert_panics!({ assert_8th!(tuple, value > &8) }, message == "assertion failed: `value > &8` where `value = tuple.8`\n  left: 8\n right: 8");
+        assert_panics!({ assert_8th!(tuple, value >= &9) }, message == "assertion failed: `value >= &9` where `value = tuple.8`\n  left: 8\n right: 9");
+    }
+
+    #[test]
+    fn _09th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_9th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.9`\n  left: 9\n right: 99");
+        assert_panics!({ assert_9th!(tuple, value != &9) }, message == "assertion failed: `value != &9` where `value = tuple.9`\n  left: 9\n right: 9");
+        assert_panics!({ assert_9th!(tuple, value < &9) }, message == "assertion failed: `value < &9` where `value = tuple.9`\n  left: 9\n right: 9");
+        assert_panics!({ assert_9th!(tuple, value <= &8) }, message == "assertion failed: `value <= &8` where `value = tuple.9`\n  left: 9\n right: 8");
+        assert_panics!({ assert_9th!(tuple, value > &9) }, message == "assertion failed: `value > &9` where `value = tuple.9`\n  left: 9\n right: 9");
+        assert_panics!({ assert_9th!(tuple, value >= &10) }, message == "assertion failed: `value >= &10` where `value = tuple.9`\n  left: 9\n right: 10");
+    }
+
+    #[test]
+    fn _10th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_10th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.10`\n  left: 10\n right: 99");
+        assert_panics!({ assert_10th!(tuple, value != &10) }, message == "assertion failed: `value != &10` where `value = tuple.10`\n  left: 10\n right: 10");
+        assert_panics!({ assert_10th!(tuple, value < &10) }, message == "assertion failed: `value < &10` where `value = tuple.10`\n  left: 10\n right: 10");
+        assert_panics!({ assert_10th!(tuple, value <= &9) }, message == "assertion failed: `value <= &9` where `value = tuple.10`\n  left: 10\n right: 9");
+        assert_panics!({ assert_10th!(tuple, value > &10) }, message == "assertion failed: `value > &10` where `value = tuple.10`\n  left: 10\n right: 10");
+        assert_panics!({ assert_10th!(tuple, value >= &11) }, message == "assertion failed: `value >= &11` where `value = tuple.10`\n  left: 10\n right: 11");
+    }
+
+    #[test]
+    fn _11th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_11th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.11`\n  left: 11\n right: 99");
+        assert_panics!({ assert_11th!(tuple, value != &11) }, message == "assertion failed: `value != &11` where `value = tuple.11`\n  left: 11\n right: 11");
+        assert_panics!({ assert_11th!(tuple, value < &11) }, message == "assertion failed: `value < &11` where `value = tuple.11`\n  left: 11\n right: 11");
+        assert_panics!({ assert_11th!(tuple, value <= &10) }, message == "assertion failed: `value <= &10` where `value = tuple.11`\n  left: 11\n right: 10");
+        assert_panics!({ assert_11th!(tuple, value > &11) }, message == "assertion failed: `value > &11` where `value = tuple.11`\n  left: 11\n right: 11");
+        assert_panics!({ assert_11th!(tuple, value >= &12) }, message == "assertion failed: `value >= &12` where `value = tuple.11`\n  left: 11\n right: 12");
+    }
+
+    #[test]
+    fn _12th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_12th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.12`\n  left: 12\n right: 99");
+        assert_panics!({ assert_12th!(tuple, value != &12) }, message == "assertion failed: `value != &12` where `value = tuple.12`\n  left: 12\n right: 12");
+        assert_panics!({ assert_12th!(tuple, value < &12) }, message == "assertion failed: `value < &12` where `value = tuple.12`\n  left: 12\n right: 12");
+        assert_panics!({ assert_12th!(tuple, value <= &11) }, message == "assertion failed: `value <= &11` where `value = tuple.12`\n  left: 12\n right: 11");
+        assert_panics!({ assert_12th!(tuple, value > &12) }, message == "assertion failed: `value > &12` where `value = tuple.12`\n  left: 12\n right: 12");
+        assert_panics!({ assert_12th!(tuple, value >= &13) }, message == "assertion failed: `value >= &13` where `value = tuple.12`\n  left: 12\n right: 13");
+    }
+
+    #[test]
+    fn _13th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_13th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.13`\n  left: 13\n right: 99");
+        assert_panics!({ assert_13th!(tuple, value != &13) }, message == "assertion failed: `value != &13` where `value = tuple.13`\n  left: 13\n right: 13");
+        assert_panics!({ assert_13th!(tuple, value < &13) }, message == "assertion failed: `value < &13` where `value = tuple.13`\n  left: 13\n right: 13");
+        assert_panics!({ assert_13th!(tuple, value <= &12) }, message == "assertion failed: `value <= &12` where `value = tuple.13`\n  left: 13\n right: 12");
+        assert_panics!({ assert_13th!(tuple, value > &13) }, message == "assertion failed: `value > &13` where `value = tuple.13`\n  left: 13\n right: 13");
+        assert_panics!({ assert_13th!(tuple, value >= &14) }, message == "assertion failed: `value >= &14` where `value = tuple.13`\n  left: 13\n right: 14");
+    }
+
+    #[test]
+    fn _14th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_14th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.14`\n  left: 14\n right: 99");
+        assert_panics!({ assert_14th!(tuple, value != &14) }, message == "assertion failed: `value != &14` where `value = tuple.14`\n  left: 14\n right: 14");
+        assert_panics!({ assert_14th!(tuple, value < &14) }, message == "assertion failed: `value < &14` where `value = tuple.14`\n  left: 14\n right: 14");
+        assert_panics!({ assert_14th!(tuple, value <= &13) }, message == "assertion failed: `value <= &13` where `value = tuple.14`\n  left: 14\n right: 13");
+        assert_panics!({ assert_14th!(tuple, value > &14) }, message == "assertion failed: `value > &14` where `value = tuple.14`\n  left: 14\n right: 14");
+        assert_panics!({ assert_14th!(tuple, value >= &15) }, message == "assertion failed: `value >= &15` where `value = tuple.14`\n  left: 14\n right: 15");
+    }
+
+    #[test]
+    fn _15th() {
+        let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_panics!({ assert_15th!(tuple, value == &99) }, message == "assertion failed: `value == &99` where `value = tuple.15`\n  left: 15\n right: 99");
+        assert_panics!({ assert_15th!(tuple, value != &15) }, message == "assertion failed: `value != &15` where `value = tuple.15`\n  left: 15\n right: 15");
+        assert_panics!({ assert_15th!(tuple, value < &15) }, message == "assertion failed: `value < &15` where `value = tuple.15`\n  left: 15\n right: 15");
+        assert_panics!({ assert_15th!(tuple, value <= &14) }, message == "assertion failed: `value <= &14` where `value = tuple.15`\n  left: 15\n right: 14");
+        assert_panics!({ assert_15th!(tuple, value > &15) }, message == "assertion failed: `value > &15` where `value = tuple.15`\n  left: 15\n right: 15");
+        assert_panics!({ assert_15th!(tuple, value >= &16) }, message == "assertion failed: `value >= &16` where `value = tuple.15`\n  left: 15\n right: 16");
+    }
+}
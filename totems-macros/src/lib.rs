@@ -0,0 +1,837 @@
+//! Procedural macros for [totems](https://docs.rs/totems).
+//!
+//! This crate is an implementation detail. Its macros are re-exported by `totems`, and should be
+//! used from there.
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use std::iter::FromIterator;
+
+//=============================================================================================
+// Macros
+//=============================================================================================
+
+/// Asserts that a boolean expression is true, rendering the value of every sub-expression on
+/// failure.
+///
+/// Documented on `totems::check`, which passes its `$crate` in brackets before the expression so
+/// that the generated code can name the crate however it was imported.
+#[proc_macro]
+pub fn check(input: TokenStream) -> TokenStream {
+    let mut tokens: Vec<TokenTree> = input.into_iter().collect();
+    let krate = match tokens.first() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+            let krate = group.stream();
+            tokens.remove(0);
+            krate
+        }
+        _ => parse("::totems"),
+    };
+    let mut segments = split(tokens, ',', 1).into_iter();
+    let expression = segments.next().unwrap_or_default();
+    let message = match segments.next() {
+        Some(arguments) if !arguments.is_empty() => {
+            let mut message = parse("::core::option::Option::Some");
+            message.extend(Some(group(Delimiter::Parenthesis, {
+                let mut format = parse("::core::format_args!");
+                format.extend(Some(group(Delimiter::Parenthesis, stream(arguments))));
+                format
+            })));
+            message
+        }
+        _ => parse("::core::option::Option::None"),
+    };
+
+    if expression.is_empty() {
+        return parse("::core::compile_error!(\"check! requires an expression\")");
+    }
+
+    match Parser::new(expression.clone()).all() {
+        Ok(mut node) => {
+            let mut printer = Printer::default();
+            printer.print(&mut node);
+            let mut generator = Generator { krate: krate.clone(), ..Generator::default() };
+            let condition = generator.generate(&node);
+            expand(&krate, &printer.text, generator.statements, condition, message)
+        }
+        // Syntax the parser does not understand is still checked, just not decomposed.
+        Err(()) => {
+            let text = stream(expression.clone()).to_string();
+            expand(&krate, &text, TokenStream::new(), stream(expression), message)
+        }
+    }
+}
+
+//=============================================================================================
+// Parsing
+//=============================================================================================
+
+/// A sub-expression, and the column under which its value is drawn.
+struct Node {
+    kind: Kind,
+    column: usize,
+}
+
+enum Kind {
+    /// A literal, which is never recorded since its value is already in the source.
+    Literal(TokenStream),
+    /// A path to a local, constant or function, which is re-evaluated rather than moved.
+    Path { tokens: TokenStream, record: bool },
+    /// An expression that is not decomposed any further: closures, blocks, macros, etc.
+    Opaque { tokens: TokenStream, bind: bool, record: bool },
+    Paren { inner: Box<Node>, span: Span },
+    Unary { op: &'static str, tokens: TokenStream, operand: Box<Node> },
+    Binary { op: &'static str, tokens: TokenStream, left: Box<Node>, right: Box<Node> },
+    Cast { operand: Box<Node>, tokens: TokenStream, ty: TokenStream },
+    Field { base: Box<Node>, name: TokenTree },
+    Index { base: Box<Node>, index: Box<Node>, span: Span },
+    MethodCall { receiver: Box<Node>, name: Ident, turbofish: TokenStream, args: Vec<Node>, span: Span },
+    Call { callee: Box<Node>, args: Vec<Node>, span: Span },
+    Try { operand: Box<Node>, tokens: TokenStream },
+}
+
+/// Methods whose return type is usually inferred from how the result is used. Their results are
+/// not recorded, since rendering them would force the type to be known too early.
+const INFERRED: &[&str] = &["into", "try_into", "parse", "collect", "sum", "product", "default"];
+
+/// Keywords that start expressions the parser does not decompose.
+const UNSUPPORTED: &[&str] = &[
+    "if", "match", "loop", "while", "for", "unsafe", "async", "return", "break", "continue", "let",
+    "yield", "box", "static",
+];
+
+/// A precedence-climbing parser over a flat list of token trees.
+///
+/// Any syntax it does not understand is an `Err(())`, in which case the caller falls back to
+/// treating the tokens as a single opaque expression.
+struct Parser {
+    tokens: Vec<TokenTree>,
+    position: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<TokenTree>) -> Self {
+        Parser { tokens, position: 0 }
+    }
+
+    /// Parses the whole token list as one expression.
+    fn all(mut self) -> Result<Node, ()> {
+        let node = self.expression(0)?;
+        match self.position == self.tokens.len() {
+            true => Ok(node),
+            false => Err(()),
+        }
+    }
+
+    fn expression(&mut self, min_precedence: u8) -> Result<Node, ()> {
+        let mut left = self.unary()?;
+        loop {
+            if self.is_ident(0, "as") && CAST_PRECEDENCE >= min_precedence {
+                let tokens = self.take(1);
+                let ty = self.ty()?;
+                left = node(Kind::Cast { operand: Box::new(left), tokens, ty });
+                continue;
+            }
+            let (op, length) = match self.binary_operator() {
+                Some(operator) => operator,
+                None => break,
+            };
+            let precedence = precedence(op);
+            if precedence < min_precedence {
+                break;
+            }
+            let tokens = self.take(length);
+            let right = self.expression(precedence + 1)?;
+            left = node(Kind::Binary { op, tokens, left: Box::new(left), right: Box::new(right) });
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Node, ()> {
+        let op = match self.punct(0) {
+            Some(('!', _)) => "!",
+            Some(('-', _)) => "-",
+            Some(('*', _)) => "*",
+            Some(('&', _)) if self.is_ident(1, "mut") => "&mut",
+            Some(('&', _)) => "&",
+            _ => {
+                let primary = self.primary()?;
+                return self.postfix(primary);
+            }
+        };
+        let tokens = self.take(if op == "&mut" { 2 } else { 1 });
+        let operand = self.unary()?;
+        Ok(node(Kind::Unary { op, tokens, operand: Box::new(operand) }))
+    }
+
+    fn primary(&mut self) -> Result<Node, ()> {
+        match self.peek(0).cloned() {
+            Some(TokenTree::Literal(_)) => Ok(node(Kind::Literal(self.take(1)))),
+            Some(TokenTree::Ident(ident)) => match ident.to_string().as_str() {
+                "true" | "false" => Ok(node(Kind::Literal(self.take(1)))),
+                "move" => Ok(self.closure()),
+                keyword if UNSUPPORTED.contains(&keyword) => Err(()),
+                _ => self.path(),
+            },
+            Some(TokenTree::Punct(punct)) => match punct.as_char() {
+                '|' => Ok(self.closure()),
+                ':' if self.is_punct(1, ':') => self.path(),
+                _ => Err(()),
+            },
+            Some(TokenTree::Group(group)) => {
+                self.position += 1;
+                let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+                match group.delimiter() {
+                    Delimiter::Parenthesis if tokens.is_empty() => Ok(node(Kind::Literal(stream(vec![group.into()])))),
+                    Delimiter::Parenthesis if split(tokens.clone(), ',', usize::MAX).len() == 1 => {
+                        match Parser::new(tokens).all() {
+                            Ok(inner) => Ok(node(Kind::Paren { inner: Box::new(inner), span: group.span() })),
+                            Err(()) => Ok(opaque(vec![group.into()])),
+                        }
+                    }
+                    Delimiter::None => Parser::new(tokens).all().or_else(|()| Ok(opaque(vec![group.into()]))),
+                    _ => Ok(opaque(vec![group.into()])),
+                }
+            }
+            None => Err(()),
+        }
+    }
+
+    fn postfix(&mut self, mut node: Node) -> Result<Node, ()> {
+        loop {
+            if self.is_punct(0, '.') && !self.is_punct(1, '.') {
+                match self.peek(1).cloned() {
+                    Some(TokenTree::Ident(name)) if name.to_string() != "await" => {
+                        self.position += 2;
+                        let turbofish = match self.is_punct(0, ':') && self.is_punct(1, ':') && self.is_punct(2, '<') {
+                            true => {
+                                let start = self.position;
+                                self.position += 2;
+                                self.generics()?;
+                                stream(self.tokens[start..self.position].to_vec())
+                            }
+                            false => TokenStream::new(),
+                        };
+                        node = match self.peek(0).cloned() {
+                            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                                self.position += 1;
+                                let args = arguments(&group);
+                                let receiver = Box::new(node);
+                                self::node(Kind::MethodCall { receiver, name, turbofish, args, span: group.span() })
+                            }
+                            _ if !turbofish.is_empty() => return Err(()),
+                            _ => self::node(Kind::Field { base: Box::new(node), name: name.into() }),
+                        };
+                    }
+                    // Nested tuple fields, `tuple.0.1`, arrive as the single float literal `0.1`.
+                    Some(TokenTree::Literal(literal)) => {
+                        self.position += 2;
+                        for index in literal.to_string().split('.') {
+                            let index: usize = index.parse().map_err(|_| ())?;
+                            let mut name = Literal::usize_unsuffixed(index);
+                            name.set_span(literal.span());
+                            node = self::node(Kind::Field { base: Box::new(node), name: name.into() });
+                        }
+                    }
+                    _ => return Err(()),
+                }
+                continue;
+            }
+            match self.peek(0).cloned() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+                    self.position += 1;
+                    let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+                    let index = Parser::new(tokens.clone()).all().unwrap_or_else(|()| opaque(tokens));
+                    node = self::node(Kind::Index { base: Box::new(node), index: Box::new(index), span: group.span() });
+                }
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    self.position += 1;
+                    let args = arguments(&group);
+                    node = self::node(Kind::Call { callee: Box::new(node), args, span: group.span() });
+                }
+                Some(TokenTree::Punct(ref punct)) if punct.as_char() == '?' => {
+                    let tokens = self.take(1);
+                    node = self::node(Kind::Try { operand: Box::new(node), tokens });
+                }
+                _ => return Ok(node),
+            }
+        }
+    }
+
+    /// Parses a path, `a::b::<T>::c`, and whatever it turns out to name: a local, a call, a
+    /// struct literal or a macro invocation.
+    fn path(&mut self) -> Result<Node, ()> {
+        let start = self.position;
+        if self.is_punct(0, ':') {
+            self.position += 2;
+        }
+        loop {
+            match self.peek(0) {
+                Some(TokenTree::Ident(_)) => self.position += 1,
+                _ => return Err(()),
+            }
+            if !(self.is_punct(0, ':') && self.is_punct(1, ':')) {
+                break;
+            }
+            self.position += 2;
+            if self.is_punct(0, '<') {
+                self.generics()?;
+                if !(self.is_punct(0, ':') && self.is_punct(1, ':')) {
+                    break;
+                }
+                self.position += 2;
+            }
+        }
+        let tokens = stream(self.tokens[start..self.position].to_vec());
+        match (self.peek(0), self.peek(1)) {
+            (Some(TokenTree::Punct(bang)), Some(TokenTree::Group(_))) if bang.as_char() == '!' => {
+                self.position += 2;
+                Ok(opaque(self.tokens[start..self.position].to_vec()))
+            }
+            (Some(TokenTree::Group(group)), _) if group.delimiter() == Delimiter::Brace => {
+                self.position += 1;
+                let tokens = stream(self.tokens[start..self.position].to_vec());
+                Ok(node(Kind::Opaque { tokens, bind: true, record: false }))
+            }
+            (Some(TokenTree::Group(group)), _) if group.delimiter() == Delimiter::Parenthesis => {
+                let record = false;
+                Ok(node(Kind::Path { tokens, record }))
+            }
+            _ => Ok(node(Kind::Path { tokens, record: true })),
+        }
+    }
+
+    /// Consumes the rest of the tokens as a closure, whose body extends as far as it can.
+    fn closure(&mut self) -> Node {
+        let tokens = self.tokens[self.position..].to_vec();
+        self.position = self.tokens.len();
+        node(Kind::Opaque { tokens: stream(tokens), bind: false, record: false })
+    }
+
+    /// Parses the target type of a cast, `x as u64`.
+    fn ty(&mut self) -> Result<TokenStream, ()> {
+        let start = self.position;
+        loop {
+            match self.peek(0) {
+                Some(TokenTree::Ident(_)) => self.position += 1,
+                _ => return Err(()),
+            }
+            if self.is_punct(0, '<') {
+                self.generics()?;
+            }
+            if !(self.is_punct(0, ':') && self.is_punct(1, ':')) {
+                break;
+            }
+            self.position += 2;
+        }
+        Ok(stream(self.tokens[start..self.position].to_vec()))
+    }
+
+    /// Consumes a balanced list of generic arguments, `<...>`.
+    fn generics(&mut self) -> Result<(), ()> {
+        let mut depth = 0;
+        while let Some(token) = self.peek(0) {
+            if let TokenTree::Punct(punct) = token {
+                match punct.as_char() {
+                    '<' => depth += 1,
+                    '>' => depth -= 1,
+                    _ => {}
+                }
+            }
+            self.position += 1;
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+        Err(())
+    }
+
+    /// Recognizes the binary operator at the current position, and how many tokens it spans.
+    fn binary_operator(&self) -> Option<(&'static str, usize)> {
+        let (first, spacing) = self.punct(0)?;
+        if spacing == Spacing::Joint {
+            let second = self.punct(1).map(|(c, _)| c);
+            let double = match (first, second) {
+                ('=', Some('=')) => Some("=="),
+                ('!', Some('=')) => Some("!="),
+                ('<', Some('=')) => Some("<="),
+                ('>', Some('=')) => Some(">="),
+                ('&', Some('&')) => Some("&&"),
+                ('|', Some('|')) => Some("||"),
+                ('<', Some('<')) => Some("<<"),
+                ('>', Some('>')) => Some(">>"),
+                _ => None,
+            };
+            if let Some(op) = double {
+                // Shifting assignments, `<<=` and `>>=`, are not expressions worth checking.
+                let assignment = self.punct(1).map(|(_, s)| s) == Some(Spacing::Joint) && self.is_punct(2, '=');
+                return if assignment { None } else { Some((op, 2)) };
+            }
+            if second == Some('=') || second == Some('.') {
+                return None;
+            }
+        }
+        let single = match first {
+            '<' => "<",
+            '>' => ">",
+            '+' => "+",
+            '-' => "-",
+            '*' => "*",
+            '/' => "/",
+            '%' => "%",
+            '&' => "&",
+            '|' => "|",
+            '^' => "^",
+            _ => return None,
+        };
+        Some((single, 1))
+    }
+
+    fn peek(&self, offset: usize) -> Option<&TokenTree> {
+        self.tokens.get(self.position + offset)
+    }
+
+    fn punct(&self, offset: usize) -> Option<(char, Spacing)> {
+        match self.peek(offset) {
+            Some(TokenTree::Punct(punct)) => Some((punct.as_char(), punct.spacing())),
+            _ => None,
+        }
+    }
+
+    fn is_punct(&self, offset: usize, c: char) -> bool {
+        self.punct(offset).map(|(p, _)| p) == Some(c)
+    }
+
+    fn is_ident(&self, offset: usize, name: &str) -> bool {
+        match self.peek(offset) {
+            Some(TokenTree::Ident(ident)) => ident.to_string() == name,
+            _ => false,
+        }
+    }
+
+    fn take(&mut self, count: usize) -> TokenStream {
+        let tokens = self.tokens[self.position..self.position + count].to_vec();
+        self.position += count;
+        stream(tokens)
+    }
+}
+
+const CAST_PRECEDENCE: u8 = 10;
+
+fn precedence(op: &str) -> u8 {
+    match op {
+        "||" => 1,
+        "&&" => 2,
+        "==" | "!=" | "<" | "<=" | ">" | ">=" => 3,
+        "|" => 4,
+        "^" => 5,
+        "&" => 6,
+        "<<" | ">>" => 7,
+        "+" | "-" => 8,
+        _ => 9,
+    }
+}
+
+fn node(kind: Kind) -> Node {
+    Node { kind, column: 0 }
+}
+
+fn opaque(tokens: Vec<TokenTree>) -> Node {
+    node(Kind::Opaque { tokens: stream(tokens), bind: true, record: true })
+}
+
+/// Parses the comma-separated arguments of a call.
+fn arguments(group: &Group) -> Vec<Node> {
+    split(group.stream().into_iter().collect(), ',', usize::MAX)
+        .into_iter()
+        .filter(|argument| !argument.is_empty())
+        .map(|argument| Parser::new(argument.clone()).all().unwrap_or_else(|()| opaque(argument)))
+        .collect()
+}
+
+/// Splits `tokens` on at most `limit` of the top-level `separator` puncts.
+fn split(tokens: Vec<TokenTree>, separator: char, limit: usize) -> Vec<Vec<TokenTree>> {
+    let mut segments = vec![Vec::new()];
+    for token in tokens {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == separator && segments.len() <= limit => {
+                segments.push(Vec::new());
+            }
+            token => segments.last_mut().unwrap().push(token),
+        }
+    }
+    segments
+}
+
+//=============================================================================================
+// Printing
+//=============================================================================================
+
+/// Prints the expression as it appears in failure messages, assigning each node the column
+/// under which its value is drawn.
+#[derive(Default)]
+struct Printer {
+    text: String,
+    column: usize,
+}
+
+impl Printer {
+    fn print(&mut self, node: &mut Node) {
+        node.column = self.column;
+        match &mut node.kind {
+            Kind::Literal(tokens) | Kind::Opaque { tokens, .. } => self.push(&tokens.to_string()),
+            Kind::Path { tokens, .. } => self.push(&compact(tokens)),
+            Kind::Paren { inner, .. } => {
+                self.push("(");
+                self.print(inner);
+                self.push(")");
+            }
+            Kind::Unary { op, operand, .. } => {
+                self.push(op);
+                if *op == "&mut" {
+                    self.push(" ");
+                }
+                self.print(operand);
+            }
+            Kind::Binary { op, left, right, .. } => {
+                self.print(left);
+                self.push(" ");
+                node.column = self.column;
+                self.push(op);
+                self.push(" ");
+                self.print(right);
+            }
+            Kind::Cast { operand, ty, .. } => {
+                self.print(operand);
+                self.push(" ");
+                node.column = self.column;
+                self.push("as ");
+                self.push(&compact(ty));
+            }
+            Kind::Field { base, name } => {
+                self.print(base);
+                self.push(".");
+                node.column = self.column;
+                self.push(&name.to_string());
+            }
+            Kind::Index { base, index, .. } => {
+                self.print(base);
+                node.column = self.column;
+                self.push("[");
+                self.print(index);
+                self.push("]");
+            }
+            Kind::MethodCall { receiver, name, turbofish, args, .. } => {
+                self.print(receiver);
+                self.push(".");
+                node.column = self.column;
+                self.push(&name.to_string());
+                self.push(&compact(turbofish));
+                self.arguments(args);
+            }
+            Kind::Call { callee, args, .. } => {
+                self.print(callee);
+                if let Kind::Path { .. } = callee.kind {
+                    node.column = callee.column;
+                } else {
+                    node.column = self.column;
+                }
+                self.arguments(args);
+            }
+            Kind::Try { operand, .. } => {
+                self.print(operand);
+                node.column = self.column;
+                self.push("?");
+            }
+        }
+    }
+
+    fn arguments(&mut self, args: &mut [Node]) {
+        self.push("(");
+        for (i, arg) in args.iter_mut().enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            self.print(arg);
+        }
+        self.push(")");
+    }
+
+    fn push(&mut self, text: &str) {
+        self.text.push_str(text);
+        self.column += text.chars().count();
+    }
+}
+
+/// Prints paths and types without the spaces `TokenStream::to_string` puts between puncts.
+fn compact(tokens: &TokenStream) -> String {
+    let mut text = String::new();
+    let mut word = false;
+    for token in tokens.clone() {
+        let is_word = matches!(token, TokenTree::Ident(_) | TokenTree::Literal(_));
+        if is_word && word {
+            text.push(' ');
+        }
+        text.push_str(&token.to_string());
+        if let TokenTree::Punct(ref punct) = token {
+            if punct.as_char() == ',' {
+                text.push(' ');
+            }
+        }
+        word = is_word;
+    }
+    text
+}
+
+//=============================================================================================
+// Code Generation
+//=============================================================================================
+
+/// Generates statements that evaluate every sub-expression exactly once, in source order,
+/// recording the rendered value of each.
+#[derive(Default)]
+struct Generator {
+    krate: TokenStream,
+    statements: TokenStream,
+    temporaries: usize,
+}
+
+impl Generator {
+    /// Emits the statements evaluating `node`, and returns the tokens that refer to its value.
+    fn generate(&mut self, node: &Node) -> TokenStream {
+        match &node.kind {
+            Kind::Literal(tokens) => tokens.clone(),
+            Kind::Path { tokens, record } => {
+                if *record {
+                    self.record(node.column, tokens.clone());
+                }
+                tokens.clone()
+            }
+            Kind::Opaque { tokens, bind, record } => {
+                if !*bind {
+                    return tokens.clone();
+                }
+                let value = self.bind(tokens.clone());
+                if *record {
+                    self.record(node.column, value.clone());
+                }
+                value
+            }
+            Kind::Paren { inner, span } => {
+                let inner = self.generate(inner);
+                stream(vec![spanned(Delimiter::Parenthesis, inner, *span)])
+            }
+            Kind::Unary { op, tokens, operand } => {
+                let literal = matches!(operand.kind, Kind::Literal(_));
+                let mut expression = tokens.clone();
+                expression.extend(self.generate(operand));
+                match *op {
+                    // References and dereferences are places, so they stay inline.
+                    "&" | "&mut" | "*" => expression,
+                    "-" if literal => expression,
+                    _ => self.bind_and_record(node.column, expression),
+                }
+            }
+            Kind::Binary { op, tokens, left, right } => {
+                let mut expression = self.generate(left);
+                expression.extend(tokens.clone());
+                if *op == "&&" || *op == "||" {
+                    // The right operand only runs, and is only recorded, if it is reached.
+                    let outer = std::mem::replace(&mut self.statements, TokenStream::new());
+                    let value = self.generate(right);
+                    let mut block = std::mem::replace(&mut self.statements, outer);
+                    block.extend(value);
+                    expression.extend(Some(group(Delimiter::Brace, block)));
+                } else {
+                    expression.extend(self.generate(right));
+                }
+                self.bind_and_record(node.column, expression)
+            }
+            Kind::Cast { operand, tokens, ty } => {
+                let mut expression = self.generate(operand);
+                expression.extend(tokens.clone());
+                expression.extend(ty.clone());
+                self.bind_and_record(node.column, expression)
+            }
+            Kind::Field { base, name } => {
+                let mut place = self.generate(base);
+                place.extend(vec![TokenTree::from(Punct::new('.', Spacing::Alone)), name.clone()]);
+                self.record(node.column, place.clone());
+                place
+            }
+            Kind::Index { base, index: operand, span } => {
+                let mut place = self.generate(base);
+                let index = self.generate(operand);
+                place.extend(Some(spanned(Delimiter::Bracket, index, *span)));
+                if let Kind::Opaque { .. } = operand.kind {
+                    // An opaque index, like a range, may not be `Copy`, so it can only be used
+                    // once. Borrowing the indexed place keeps it from being moved twice.
+                    let mut reference = parse("&");
+                    reference.extend(place);
+                    let mut place = parse("*");
+                    place.extend(self.bind(reference));
+                    let place = stream(vec![group(Delimiter::Parenthesis, place)]);
+                    self.record(node.column, place.clone());
+                    return place;
+                }
+                self.record(node.column, place.clone());
+                place
+            }
+            Kind::MethodCall { receiver, name, turbofish, args, span } => {
+                let mut expression = self.generate(receiver);
+                expression.extend(vec![TokenTree::from(Punct::new('.', Spacing::Alone)), name.clone().into()]);
+                expression.extend(turbofish.clone());
+                expression.extend(Some(self.arguments(args, *span)));
+                let value = self.bind(expression);
+                if turbofish.is_empty() && INFERRED.contains(&name.to_string().as_str()) {
+                    return value;
+                }
+                self.record(node.column, value.clone());
+                value
+            }
+            Kind::Call { callee, args, span } => {
+                let mut expression = self.generate(callee);
+                let inferred = match &callee.kind {
+                    Kind::Path { tokens, .. } => INFERRED.contains(&tokens.to_string().rsplit(' ').next().unwrap_or_default()),
+                    _ => false,
+                };
+                expression.extend(Some(self.arguments(args, *span)));
+                let value = self.bind(expression);
+                if !inferred {
+                    self.record(node.column, value.clone());
+                }
+                value
+            }
+            Kind::Try { operand, tokens } => {
+                let mut expression = self.generate(operand);
+                expression.extend(tokens.clone());
+                self.bind_and_record(node.column, expression)
+            }
+        }
+    }
+
+    fn arguments(&mut self, args: &[Node], span: Span) -> TokenTree {
+        let mut list = TokenStream::new();
+        for arg in args {
+            list.extend(self.generate(arg));
+            list.extend(Some(TokenTree::from(Punct::new(',', Spacing::Alone))));
+        }
+        spanned(Delimiter::Parenthesis, list, span)
+    }
+
+    fn bind_and_record(&mut self, column: usize, expression: TokenStream) -> TokenStream {
+        let value = self.bind(expression);
+        self.record(column, value.clone());
+        value
+    }
+
+    /// Emits `let mut __totems_N = expression;` and returns the temporary.
+    fn bind(&mut self, expression: TokenStream) -> TokenStream {
+        let expression = unparenthesize(expression);
+        let temporary = Ident::new(&format!("__totems_{}", self.temporaries), Span::mixed_site());
+        self.temporaries += 1;
+        self.statements.extend(parse("#[allow(unused_mut)] let mut"));
+        self.statements.extend(Some(TokenTree::from(temporary.clone())));
+        self.statements.extend(Some(TokenTree::from(Punct::new('=', Spacing::Alone))));
+        self.statements.extend(expression);
+        self.statements.extend(Some(TokenTree::from(Punct::new(';', Spacing::Alone))));
+        stream(vec![temporary.into()])
+    }
+
+    /// Emits a statement pushing the rendered value of `place` onto the log.
+    fn record(&mut self, column: usize, place: TokenStream) {
+        let mut render = path(&self.krate, "::check::Render");
+        render.extend(Some(group(Delimiter::Parenthesis, {
+            let mut reference = parse("&");
+            reference.extend(Some(group(Delimiter::Parenthesis, place)));
+            reference
+        })));
+        let mut entry = TokenStream::from(TokenTree::from(Literal::usize_unsuffixed(column)));
+        entry.extend(parse(","));
+        entry.extend(Some(group(Delimiter::Parenthesis, {
+            let mut receiver = parse("&");
+            receiver.extend(render);
+            receiver
+        })));
+        entry.extend(parse(".render()"));
+
+        self.statements.extend(Some(TokenTree::from(log())));
+        self.statements.extend(parse(".push"));
+        self.statements.extend(Some(group(Delimiter::Parenthesis, stream(vec![group(Delimiter::Parenthesis, entry)]))));
+        self.statements.extend(parse(";"));
+    }
+}
+
+/// Wraps the generated statements into the final block, which fails if `condition` is false.
+fn expand(krate: &TokenStream, text: &str, statements: TokenStream, condition: TokenStream, message: TokenStream) -> TokenStream {
+    let mut body = parse("use");
+    body.extend(path(krate, "::check::{RenderDebug as _, RenderFallback as _}; #[allow(unused_mut)] let mut"));
+    body.extend(Some(TokenTree::from(log())));
+    body.extend(parse(":"));
+    body.extend(path(krate, "::check::Log ="));
+    body.extend(path(krate, "::check::Log::new();"));
+    body.extend(statements);
+    body.extend(parse("if !"));
+    body.extend(Some(group(Delimiter::Parenthesis, condition)));
+    body.extend(Some(group(Delimiter::Brace, {
+        let mut failure = TokenStream::from(TokenTree::from(Literal::string(text)));
+        failure.extend(parse(", &"));
+        failure.extend(Some(TokenTree::from(log())));
+        failure.extend(parse(","));
+        failure.extend(message);
+        let mut fail = path(krate, "::check::fail");
+        fail.extend(Some(group(Delimiter::Parenthesis, failure)));
+        fail
+    })));
+    stream(vec![group(Delimiter::Brace, body)])
+}
+
+//=============================================================================================
+// Helpers
+//=============================================================================================
+
+/// Strips the parentheses around an expression that is bound to a temporary, which would
+/// otherwise trip the `unused_parens` lint on the user's tokens.
+fn unparenthesize(expression: TokenStream) -> TokenStream {
+    let mut tokens: Vec<TokenTree> = expression.clone().into_iter().collect();
+    match tokens.pop() {
+        Some(TokenTree::Group(group))
+            if tokens.is_empty()
+                && group.delimiter() == Delimiter::Parenthesis
+                && split(group.stream().into_iter().collect(), ',', usize::MAX).len() == 1 =>
+        {
+            group.stream()
+        }
+        _ => expression,
+    }
+}
+
+fn log() -> Ident {
+    Ident::new("__totems_log", Span::mixed_site())
+}
+
+/// Returns `krate` followed by the tokens of `rest`, like `$crate::check::Log`.
+fn path(krate: &TokenStream, rest: &str) -> TokenStream {
+    let mut path = krate.clone();
+    path.extend(parse(rest));
+    path
+}
+
+fn parse(source: &str) -> TokenStream {
+    source.parse().expect("generated tokens should parse")
+}
+
+fn stream(tokens: Vec<TokenTree>) -> TokenStream {
+    TokenStream::from_iter(tokens)
+}
+
+fn group(delimiter: Delimiter, tokens: TokenStream) -> TokenTree {
+    Group::new(delimiter, tokens).into()
+}
+
+fn spanned(delimiter: Delimiter, tokens: TokenStream, span: Span) -> TokenTree {
+    let mut group = Group::new(delimiter, tokens);
+    group.set_span(span);
+    group.into()
+}